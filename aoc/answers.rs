@@ -0,0 +1,253 @@
+use std::{fmt, panic};
+
+use crate::{Day, Inputs, Part};
+
+// dayNN/answers.toml lists the expected answers per input variant:
+//
+//   [test]
+//   part1 = 10092
+//   part2 = 9021
+//
+// every variant has to state both parts, "skip" opts out of a part explicitly
+// while 0, "" and "todo" count as placeholders and fail the check
+pub const MANIFEST: &str = "answers.toml";
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expected {
+    Answer(String),
+    Skip,
+    Placeholder(String),
+    Missing,
+}
+
+impl Expected {
+    fn from_value(value: &toml::Value) -> Result<Self, String> {
+        match value {
+            toml::Value::Integer(0) => Ok(Expected::Placeholder("0".to_string())),
+            toml::Value::Integer(i) => Ok(Expected::Answer(i.to_string())),
+            toml::Value::String(s) if s == "skip" => Ok(Expected::Skip),
+            toml::Value::String(s) if s.is_empty() || s.eq_ignore_ascii_case("todo") => {
+                Ok(Expected::Placeholder(format!("\"{}\"", s)))
+            }
+            toml::Value::String(s) => Ok(Expected::Answer(s.clone())),
+            v => Err(format!("unsupported answer {}", v)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Example {
+    pub variant: String,
+    pub part1: Expected,
+    pub part2: Expected,
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> &Expected {
+        match part {
+            Part::One => &self.part1,
+            Part::Two => &self.part2,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Manifest {
+    pub examples: Vec<Example>,
+}
+
+impl Manifest {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table: toml::Table = text
+            .parse()
+            .map_err(|e: toml::de::Error| e.message().to_string())?;
+
+        let mut examples = Vec::new();
+        for (variant, entry) in table.iter() {
+            let entry = entry
+                .as_table()
+                .ok_or_else(|| format!("[{}] must be a table", variant))?;
+
+            let mut example = Example {
+                variant: variant.clone(),
+                part1: Expected::Missing,
+                part2: Expected::Missing,
+            };
+
+            for (key, value) in entry.iter() {
+                let expected = Expected::from_value(value)
+                    .map_err(|e| format!("[{}] {}: {}", variant, key, e))?;
+                match key.as_str() {
+                    "part1" => example.part1 = expected,
+                    "part2" => example.part2 = expected,
+                    _ => return Err(format!("[{}] unknown key '{}'", variant, key)),
+                }
+            }
+
+            examples.push(example);
+        }
+
+        Ok(Manifest { examples })
+    }
+
+    pub fn load(inputs: &Inputs, day: u8) -> Result<Self, String> {
+        let path = inputs.day_dir(day).join(MANIFEST);
+        let text =
+            std::fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        Manifest::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    Pass,
+    Skipped,
+    Fail { expected: String, actual: String },
+    Missing(String),
+    Placeholder(String),
+    Panicked(String),
+    Error(String),
+}
+
+impl Outcome {
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, Outcome::Pass | Outcome::Skipped)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Check {
+    pub day: u8,
+    pub variant: String,
+    pub part: Option<Part>,
+    pub outcome: Outcome,
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "day{:02} {}", self.day, self.variant)?;
+        if let Some(part) = self.part {
+            write!(f, " {}", part)?;
+        }
+
+        match &self.outcome {
+            Outcome::Pass => write!(f, ": ok"),
+            Outcome::Skipped => write!(f, ": skipped"),
+            Outcome::Fail { expected, actual } => {
+                write!(f, ": expected {}, got {}", expected, actual)
+            }
+            Outcome::Missing(what) => write!(f, ": missing {}", what),
+            Outcome::Placeholder(value) => write!(f, ": placeholder expectation {}", value),
+            Outcome::Panicked(msg) => write!(f, ": panicked, {}", msg),
+            Outcome::Error(msg) => write!(f, ": {}", msg),
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        return s.to_string();
+    }
+    if let Some(s) = payload.downcast_ref::<String>() {
+        return s.clone();
+    }
+    "unknown panic".to_string()
+}
+
+// runs every entry of the manifest of a day
+pub fn check_day(day: &Day, inputs: &Inputs) -> Vec<Check> {
+    let failed = |variant: &str, outcome| Check {
+        day: day.number,
+        variant: variant.to_string(),
+        part: None,
+        outcome,
+    };
+
+    let manifest = match Manifest::load(inputs, day.number) {
+        Ok(manifest) => manifest,
+        Err(e) => return vec![failed(MANIFEST, Outcome::Missing(e))],
+    };
+
+    if manifest.examples.is_empty() {
+        return vec![failed(MANIFEST, Outcome::Missing("examples".to_string()))];
+    }
+
+    let mut checks = Vec::new();
+    for example in manifest.examples.iter() {
+        let input = match inputs.load(day.number, &example.variant) {
+            Ok(input) => input,
+            Err(e) => {
+                checks.push(failed(&example.variant, Outcome::Missing(e.to_string())));
+                continue;
+            }
+        };
+
+        for part in [Part::One, Part::Two] {
+            let outcome = match example.expected(part) {
+                Expected::Skip => Outcome::Skipped,
+                Expected::Missing => Outcome::Missing("expectation".to_string()),
+                Expected::Placeholder(value) => Outcome::Placeholder(value.clone()),
+                Expected::Answer(expected) => {
+                    // one part at a time, a panic in part 1 still lets part 2 run
+                    let solve =
+                        || (day.solve)(&input, &[part]).map(|answers| answers[0].value.clone());
+                    match panic::catch_unwind(solve) {
+                        Ok(Ok(actual)) if &actual == expected => Outcome::Pass,
+                        Ok(Ok(actual)) => Outcome::Fail {
+                            expected: expected.clone(),
+                            actual,
+                        },
+                        Ok(Err(e)) => Outcome::Error(e.to_string()),
+                        Err(payload) => Outcome::Panicked(panic_message(payload)),
+                    }
+                }
+            };
+
+            checks.push(Check {
+                day: day.number,
+                variant: example.variant.clone(),
+                part: Some(part),
+                outcome,
+            });
+        }
+    }
+
+    checks
+}
+
+#[test]
+fn parse_manifest() {
+    let manifest = Manifest::parse(
+        r#"
+        [test]
+        part1 = 10092
+        part2 = "skip"
+
+        [test1]
+        part1 = 0
+        part2 = "4,6,3"
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(2, manifest.examples.len());
+    assert_eq!(
+        Expected::Answer("10092".to_string()),
+        manifest.examples[0].part1
+    );
+    assert_eq!(Expected::Skip, manifest.examples[0].part2);
+    assert_eq!(
+        Expected::Placeholder("0".to_string()),
+        manifest.examples[1].part1
+    );
+    assert_eq!(
+        Expected::Answer("4,6,3".to_string()),
+        manifest.examples[1].part2
+    );
+
+    let manifest = Manifest::parse("[test]\npart1 = 12\n").unwrap();
+    assert_eq!(Expected::Missing, manifest.examples[0].part2);
+
+    assert!(Manifest::parse("[test]\npart3 = 12\n").is_err());
+    assert!(Manifest::parse("part1 = 12\n").is_err());
+}
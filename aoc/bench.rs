@@ -0,0 +1,162 @@
+use std::{
+    fmt::Write,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{Day, ParseError, Solution};
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+// one timed run of parse, part1 and part2
+pub fn time<S: Solution>(input: &str) -> Result<Timings, ParseError> {
+    let now = Instant::now();
+    let parsed = black_box(S::parse(black_box(input))?);
+    let parse = now.elapsed();
+
+    let now = Instant::now();
+    black_box(S::part1(&parsed).to_string());
+    let part1 = now.elapsed();
+
+    let now = Instant::now();
+    black_box(S::part2(&parsed).to_string());
+    let part2 = now.elapsed();
+
+    Ok(Timings {
+        parse,
+        part1,
+        part2,
+    })
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        if samples.is_empty() {
+            return Stats::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        // nearest rank percentile
+        let rank = |p: usize| sorted[(n * p).div_ceil(100).max(1) - 1];
+
+        Stats {
+            runs: n,
+            min: sorted[0],
+            median: rank(50),
+            p95: rank(95),
+            mean: sorted.iter().sum::<Duration>() / n as u32,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct BenchRow {
+    pub day: String,
+    pub stage: &'static str,
+    pub stats: Stats,
+}
+
+// warms up, then times every stage of a day `runs` times
+pub fn bench(
+    day: &Day,
+    input: &str,
+    warmup: usize,
+    runs: usize,
+) -> Result<Vec<BenchRow>, ParseError> {
+    for _ in 0..warmup {
+        (day.time)(input)?;
+    }
+
+    let samples = (0..runs)
+        .map(|_| (day.time)(input))
+        .collect::<Result<Vec<Timings>, _>>()?;
+
+    let stage = |stage, f: fn(&Timings) -> Duration| {
+        let durations: Vec<Duration> = samples.iter().map(f).collect();
+        BenchRow {
+            day: day.name(),
+            stage,
+            stats: Stats::from_samples(&durations),
+        }
+    };
+
+    Ok(vec![
+        stage("parse", |t| t.parse),
+        stage("part1", |t| t.part1),
+        stage("part2", |t| t.part2),
+        stage("total", |t| t.parse + t.part1 + t.part2),
+    ])
+}
+
+pub fn to_csv(rows: &[BenchRow]) -> String {
+    let mut out = String::from("day,stage,runs,min_ns,median_ns,p95_ns,mean_ns\n");
+    for row in rows {
+        let s = &row.stats;
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{}",
+            row.day,
+            row.stage,
+            s.runs,
+            s.min.as_nanos(),
+            s.median.as_nanos(),
+            s.p95.as_nanos(),
+            s.mean.as_nanos()
+        )
+        .expect("success");
+    }
+    out
+}
+
+pub fn to_json(rows: &[BenchRow]) -> String {
+    let entries: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            let s = &row.stats;
+            format!(
+                "  {{\"day\": \"{}\", \"stage\": \"{}\", \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}, \"p95_ns\": {}, \"mean_ns\": {}}}",
+                row.day,
+                row.stage,
+                s.runs,
+                s.min.as_nanos(),
+                s.median.as_nanos(),
+                s.p95.as_nanos(),
+                s.mean.as_nanos()
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]\n", entries.join(",\n"))
+}
+
+#[test]
+fn stats() {
+    let samples: Vec<Duration> = (1..=20).rev().map(Duration::from_millis).collect();
+    let stats = Stats::from_samples(&samples);
+
+    assert_eq!(20, stats.runs);
+    assert_eq!(Duration::from_millis(1), stats.min);
+    assert_eq!(Duration::from_millis(10), stats.median);
+    assert_eq!(Duration::from_millis(19), stats.p95);
+    assert_eq!(Duration::from_micros(10500), stats.mean);
+
+    let stats = Stats::from_samples(&[Duration::from_millis(3)]);
+    assert_eq!(Duration::from_millis(3), stats.p95);
+}
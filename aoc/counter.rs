@@ -0,0 +1,124 @@
+use std::{
+    collections::{hash_map, HashMap},
+    hash::Hash,
+};
+
+// a multiset, how often every item was added
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Counter<T: Hash + Eq> {
+    counts: HashMap<T, usize>,
+    total: usize,
+}
+
+impl<T: Hash + Eq> Default for Counter<T> {
+    fn default() -> Self {
+        Counter {
+            counts: HashMap::new(),
+            total: 0,
+        }
+    }
+}
+
+impl<T: Hash + Eq> Counter<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, item: T) {
+        self.add_n(item, 1);
+    }
+
+    pub fn add_n(&mut self, item: T, n: usize) {
+        if n > 0 {
+            *self.counts.entry(item).or_insert(0) += n;
+            self.total += n;
+        }
+    }
+
+    // zero for items never added
+    pub fn count(&self, item: &T) -> usize {
+        self.counts.get(item).copied().unwrap_or(0)
+    }
+
+    // the sum of all counts
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    // the number of distinct items
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    // the n items with the highest counts, highest first
+    pub fn most_common(&self, n: usize) -> Vec<(&T, usize)> {
+        let mut items: Vec<(&T, usize)> = self.iter().collect();
+        items.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        items.truncate(n);
+        items
+    }
+
+    pub fn merge(&mut self, other: Counter<T>) {
+        for (item, n) in other {
+            self.add_n(item, n);
+        }
+    }
+
+    // in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&T, usize)> + '_ {
+        self.counts.iter().map(|(item, &n)| (item, n))
+    }
+}
+
+impl<T: Hash + Eq> FromIterator<T> for Counter<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut counter = Counter::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<T: Hash + Eq> Extend<T> for Counter<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.add(item);
+        }
+    }
+}
+
+impl<T: Hash + Eq> IntoIterator for Counter<T> {
+    type Item = (T, usize);
+    type IntoIter = hash_map::IntoIter<T, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+#[test]
+fn counter() {
+    let mut letters: Counter<char> = "abracadabra".chars().collect();
+    assert_eq!(5, letters.count(&'a'));
+    assert_eq!(0, letters.count(&'z'));
+    assert_eq!((5, 11), (letters.len(), letters.total()));
+    assert_eq!(vec![(&'a', 5)], letters.most_common(1));
+
+    letters.add('z');
+    letters.add_n('d', 3);
+    letters.add_n('y', 0);
+    assert_eq!(4, letters.count(&'d'));
+    assert_eq!((6, 15), (letters.len(), letters.total()));
+
+    let mut more: Counter<char> = "zz".chars().collect();
+    more.merge(letters);
+    assert_eq!(3, more.count(&'z'));
+    assert_eq!(17, more.total());
+    assert_eq!(17, more.iter().map(|(_, n)| n).sum::<usize>());
+
+    let top: Vec<usize> = more.most_common(3).iter().map(|&(_, n)| n).collect();
+    assert_eq!(vec![5, 4, 3], top);
+}
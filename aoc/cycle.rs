@@ -0,0 +1,168 @@
+use std::{collections::HashMap, hash::Hash};
+
+// the states from step `start` on repeat every `length` steps,
+// step functions return None when the simulation ends, there is no cycle then
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    // the earliest step with the same state as `step`
+    pub fn equivalent(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+}
+
+// tortoise and hare, only compares states and keeps two of them around
+pub fn floyd<S: PartialEq>(initial: S, mut step: impl FnMut(&S) -> Option<S>) -> Option<Cycle> {
+    // the hare runs twice as fast until both meet inside of the cycle
+    let mut tortoise = step(&initial)?;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        hare = step(&hare)?;
+    }
+
+    // they meet again at its start when one of them begins from scratch
+    let mut start = 0;
+    let mut tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    let mut length = 1;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+// like floyd but with fewer steps, the tortoise teleports at powers of two
+pub fn brent<S: PartialEq + Clone>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial)?;
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    // with the hare `length` steps ahead both meet at the start of the cycle
+    let mut hare = initial.clone();
+    for _ in 0..length {
+        hare = step(&hare)?;
+    }
+    let mut start = 0;
+    let mut tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+// remembers the step every state was seen at, a single pass but all states are kept
+pub fn find_cycle<S: Hash + Eq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut state = initial;
+    let mut i = 0;
+    loop {
+        if let Some(&start) = seen.get(&state) {
+            return Some(Cycle {
+                start,
+                length: i - start,
+            });
+        }
+
+        let next = step(&state)?;
+        seen.insert(state, i);
+        state = next;
+        i += 1;
+    }
+}
+
+// the state after n steps, once the states repeat the rest is skipped,
+// None if the simulation ends before
+pub fn state_at<S: Hash + Eq + Clone>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+    n: usize,
+) -> Option<S> {
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut states: Vec<S> = Vec::new();
+    let mut state = initial;
+    for i in 0..n {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                length: i - start,
+            };
+            return Some(states[cycle.equivalent(n)].clone());
+        }
+
+        let next = step(&state)?;
+        seen.insert(state.clone(), i);
+        states.push(state);
+        state = next;
+    }
+    Some(state)
+}
+
+#[test]
+fn cycle() {
+    // 0 1 2 3 4 2 3 4 ...
+    let step = |&x: &u32| Some(if x == 4 { 2 } else { x + 1 });
+    let expected = Cycle {
+        start: 2,
+        length: 3,
+    };
+    assert_eq!(Some(expected), floyd(0, step));
+    assert_eq!(Some(expected), brent(0, step));
+    assert_eq!(Some(expected), find_cycle(0, step));
+    assert_eq!(2, expected.equivalent(1_000_000_001));
+
+    assert_eq!(Some(1), state_at(0, step, 1));
+    assert_eq!(Some(4), state_at(0, step, 7));
+    assert_eq!(Some(4), state_at(0, step, 1_000_000_000));
+
+    // the three agree on a less regular sequence
+    let step = |&x: &u64| Some((x * x + 1) % 1021);
+    let cycle = find_cycle(3, step);
+    assert!(cycle.is_some());
+    assert_eq!(cycle, floyd(3, step));
+    assert_eq!(cycle, brent(3, step));
+
+    // a simulation that stops has no cycle
+    let stops = |&x: &u32| (x < 10).then_some(x + 1);
+    assert_eq!(None, floyd(0, stops));
+    assert_eq!(None, brent(0, stops));
+    assert_eq!(None, find_cycle(0, stops));
+    assert_eq!(Some(10), state_at(0, stops, 10));
+    assert_eq!(None, state_at(0, stops, 11));
+}
@@ -0,0 +1,201 @@
+use crate::{ParseError, Signed, Vec2};
+
+// y points down, so Up is (0 -1)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Direction {
+    // clockwise, starting up
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+// arrows `<>^v`, letters `LRUD` and compass points `NESW`
+impl TryFrom<char> for Direction {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '<' | 'L' | 'W' => Ok(Direction::Left),
+            '>' | 'R' | 'E' => Ok(Direction::Right),
+            '^' | 'U' | 'N' => Ok(Direction::Up),
+            'v' | 'D' | 'S' => Ok(Direction::Down),
+            _ => Err(ParseError::new(format!("unknown direction '{}'", c))),
+        }
+    }
+}
+
+impl<T: Signed> From<Direction> for Vec2<T> {
+    fn from(value: Direction) -> Vec2<T> {
+        match value {
+            Direction::Left => Vec2::new(-T::ONE, T::ZERO),
+            Direction::Right => Vec2::new(T::ONE, T::ZERO),
+            Direction::Up => Vec2::new(T::ZERO, -T::ONE),
+            Direction::Down => Vec2::new(T::ZERO, T::ONE),
+        }
+    }
+}
+
+impl<T: Signed> From<&Direction> for Vec2<T> {
+    fn from(value: &Direction) -> Vec2<T> {
+        Vec2::from(*value)
+    }
+}
+
+// the four directions plus the diagonals in between
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    // clockwise, starting up
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    pub const DIAGONALS: [Direction8; 4] = [
+        Direction8::UpRight,
+        Direction8::DownRight,
+        Direction8::DownLeft,
+        Direction8::UpLeft,
+    ];
+
+    fn rotate(self, steps: usize) -> Self {
+        Direction8::ALL[(self as usize + steps) % 8]
+    }
+
+    // 45 degrees
+    pub fn turn_right(self) -> Self {
+        self.rotate(1)
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.rotate(7)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Left => Direction8::Left,
+            Direction::Right => Direction8::Right,
+            Direction::Up => Direction8::Up,
+            Direction::Down => Direction8::Down,
+        }
+    }
+}
+
+impl<T: Signed> From<Direction8> for Vec2<T> {
+    fn from(value: Direction8) -> Vec2<T> {
+        let (up, right, down, left) = (
+            Vec2::from(Direction::Up),
+            Vec2::from(Direction::Right),
+            Vec2::from(Direction::Down),
+            Vec2::from(Direction::Left),
+        );
+        match value {
+            Direction8::Up => up,
+            Direction8::UpRight => up + right,
+            Direction8::Right => right,
+            Direction8::DownRight => down + right,
+            Direction8::Down => down,
+            Direction8::DownLeft => down + left,
+            Direction8::Left => left,
+            Direction8::UpLeft => up + left,
+        }
+    }
+}
+
+#[test]
+fn direction() {
+    for dir in Direction::ALL {
+        assert_eq!(dir, dir.turn_left().turn_right());
+        assert_eq!(dir.opposite(), dir.turn_right().turn_right());
+        assert_eq!(
+            Vec2::<i32>::from(dir.turn_right()),
+            Vec2::from(dir).rotate_right()
+        );
+    }
+
+    let parsed: Result<Vec<Direction>, _> =
+        "<>^vLRUDWENS".chars().map(Direction::try_from).collect();
+    assert_eq!(
+        [
+            Direction::Left,
+            Direction::Right,
+            Direction::Up,
+            Direction::Down
+        ]
+        .repeat(3),
+        parsed.unwrap()
+    );
+    assert!(Direction::try_from('x').is_err());
+
+    for dir in Direction8::ALL {
+        let v = Vec2::<i32>::from(dir);
+        assert_eq!(dir.is_diagonal(), v.x != 0 && v.y != 0);
+        assert_eq!(-v, Vec2::from(dir.opposite()));
+        assert_eq!(dir, dir.turn_right().turn_left());
+    }
+    assert_eq!(Vec2::new(1, -1), Vec2::from(Direction8::UpRight));
+    assert_eq!(Direction8::Up, Direction::Up.into());
+}
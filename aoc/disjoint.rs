@@ -0,0 +1,237 @@
+use std::{collections::HashMap, hash::Hash};
+
+// union find over the elements 0..len, every element starts in its own set
+#[derive(Clone, Debug)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    ranks: Vec<u8>,
+    sizes: Vec<usize>,
+    components: usize,
+}
+
+impl DisjointSet {
+    pub fn new(len: usize) -> Self {
+        DisjointSet {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            sizes: vec![1; len],
+            components: len,
+        }
+    }
+
+    // a new element in a set of its own, returns its index
+    pub fn push(&mut self) -> usize {
+        let index = self.parents.len();
+        self.parents.push(index);
+        self.ranks.push(0);
+        self.sizes.push(1);
+        self.components += 1;
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    // the representative of the set containing a, the path to it is compressed on the way
+    pub fn find(&mut self, a: usize) -> usize {
+        let mut root = a;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut a = a;
+        while self.parents[a] != root {
+            let next = self.parents[a];
+            self.parents[a] = root;
+            a = next;
+        }
+        root
+    }
+
+    // false if both were already in the same set
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+
+        // the shallower tree goes below the deeper one
+        let (root, child) = if self.ranks[a] < self.ranks[b] {
+            (b, a)
+        } else {
+            (a, b)
+        };
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        if self.ranks[root] == self.ranks[child] {
+            self.ranks[root] += 1;
+        }
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    // the number of elements in the set containing a
+    pub fn size(&mut self, a: usize) -> usize {
+        let root = self.find(a);
+        self.sizes[root]
+    }
+
+    // the number of disjoint sets
+    pub fn components(&self) -> usize {
+        self.components
+    }
+
+    // the elements of every set, sets ordered by their smallest element
+    pub fn groups(&mut self) -> Vec<Vec<usize>> {
+        let mut group_of: HashMap<usize, usize> = HashMap::new();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for a in 0..self.len() {
+            let root = self.find(a);
+            let group = *group_of.entry(root).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(a);
+        }
+        groups
+    }
+}
+
+// union find over arbitrary keys, unknown keys are added on first use
+#[derive(Clone, Debug)]
+pub struct KeyedDisjointSet<K: Hash + Eq + Clone> {
+    set: DisjointSet,
+    indices: HashMap<K, usize>,
+    keys: Vec<K>,
+}
+
+impl<K: Hash + Eq + Clone> Default for KeyedDisjointSet<K> {
+    fn default() -> Self {
+        KeyedDisjointSet {
+            set: DisjointSet::new(0),
+            indices: HashMap::new(),
+            keys: Vec::new(),
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone> KeyedDisjointSet<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // the index of the key, a new set if it wasn't known yet
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&index) = self.indices.get(&key) {
+            return index;
+        }
+        let index = self.set.push();
+        self.indices.insert(key.clone(), index);
+        self.keys.push(key);
+        index
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.indices.contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    // the representative key of the set containing key, None for unknown keys
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let index = *self.indices.get(key)?;
+        let root = self.set.find(index);
+        Some(&self.keys[root])
+    }
+
+    // false if both were already in the same set
+    pub fn union(&mut self, a: K, b: K) -> bool {
+        let (a, b) = (self.insert(a), self.insert(b));
+        self.set.union(a, b)
+    }
+
+    // unknown keys are only connected to themselves
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.indices.get(a), self.indices.get(b)) {
+            (Some(&a), Some(&b)) => self.set.connected(a, b),
+            _ => a == b,
+        }
+    }
+
+    // the number of keys in the set containing key, zero for unknown keys
+    pub fn size(&mut self, key: &K) -> usize {
+        match self.indices.get(key) {
+            Some(&index) => self.set.size(index),
+            None => 0,
+        }
+    }
+
+    pub fn components(&self) -> usize {
+        self.set.components()
+    }
+
+    // the keys of every set, sets and keys in insertion order
+    pub fn groups(&mut self) -> Vec<Vec<K>> {
+        self.set
+            .groups()
+            .into_iter()
+            .map(|group| group.into_iter().map(|i| self.keys[i].clone()).collect())
+            .collect()
+    }
+}
+
+#[test]
+fn disjoint_set() {
+    let mut set = DisjointSet::new(6);
+    assert_eq!(6, set.components());
+    assert!(set.union(0, 1));
+    assert!(set.union(2, 3));
+    assert!(set.union(1, 3));
+    assert!(!set.union(0, 2));
+    assert!(set.connected(0, 3));
+    assert!(!set.connected(0, 4));
+    assert_eq!((4, 1), (set.size(2), set.size(5)));
+    assert_eq!(3, set.components());
+    assert_eq!(vec![vec![0, 1, 2, 3], vec![4], vec![5]], set.groups());
+
+    let index = set.push();
+    assert_eq!((6, 4), (index, set.components()));
+
+    // the plots of the AoC day12 example, joined with their equal neighbors
+    use crate::{Direction, Grid, Vec2};
+    let grid = Grid::<char>::parse("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+    let mut plots = KeyedDisjointSet::new();
+    for (pos, plant) in grid.iter_positions() {
+        plots.insert(pos);
+        for dir in Direction::ALL {
+            let next = pos + Vec2::from(dir);
+            if grid.value_for(&next) == Some(plant) {
+                plots.union(pos, next);
+            }
+        }
+    }
+    assert_eq!(5, plots.components());
+    assert_eq!(4, plots.size(&Vec2::new(3, 3)));
+    assert_eq!(0, plots.size(&Vec2::new(9, 9)));
+    assert!(plots.connected(&Vec2::new(2, 1), &Vec2::new(3, 3)));
+    assert!(!plots.connected(&Vec2::new(3, 1), &Vec2::new(3, 2)));
+    let root = plots.find(&Vec2::new(0, 3)).cloned();
+    assert_eq!(root, plots.find(&Vec2::new(2, 3)).cloned());
+    assert_eq!(None, plots.find(&Vec2::new(9, 9)));
+    assert_eq!(vec![Vec2::new(3, 1)], plots.groups()[3]);
+}
@@ -0,0 +1,117 @@
+use std::{fmt, str::FromStr};
+
+// line and column are 1 based, 0 means the position is unknown
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub source_line: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ParseError {
+            message: message.into(),
+            ..Default::default()
+        }
+    }
+
+    // `part` has to be a slice of `input`, e.g. a word of a line or a regex match
+    pub fn at(input: &str, part: &str, message: impl Into<String>) -> Self {
+        ParseError::new(message).locate(input, part)
+    }
+
+    pub fn locate(mut self, input: &str, part: &str) -> Self {
+        // only a slice of input has a position, anything else keeps the message alone
+        let begin = input.as_ptr() as usize;
+        let address = part.as_ptr() as usize;
+        if address < begin || address - begin + part.len() > input.len() {
+            return self;
+        }
+        let offset = address - begin;
+        if !input.is_char_boundary(offset) || !input.is_char_boundary(offset + part.len()) {
+            return self;
+        }
+
+        let before = &input[..offset];
+        let line_begin = before.rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = input[offset..]
+            .find('\n')
+            .map(|i| offset + i)
+            .unwrap_or(input.len());
+
+        self.line = before.matches('\n').count() + 1;
+        self.column = before[line_begin..].chars().count() + 1;
+        self.text = part.to_string();
+        self.source_line = input[line_begin..line_end]
+            .trim_end_matches('\r')
+            .to_string();
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            return write!(f, "{}", self.message);
+        }
+
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+
+        if !self.source_line.is_empty() {
+            let width = self.text.chars().count().max(1);
+            write!(
+                f,
+                "\n  {}\n  {}{}",
+                self.source_line,
+                " ".repeat(self.column - 1),
+                "^".repeat(width)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// parses a slice of `input` and points at it on failure
+pub fn parse_at<T: FromStr>(input: &str, part: &str) -> Result<T, ParseError> {
+    part.parse().map_err(|_| {
+        let message = format!("can't parse '{}' as {}", part, std::any::type_name::<T>());
+        ParseError::at(input, part, message)
+    })
+}
+
+#[test]
+fn locate() {
+    let input = "1 2\n3 x4\n5 6";
+    let line = input.lines().nth(1).unwrap();
+    let word = line.split_whitespace().nth(1).unwrap();
+
+    let e = parse_at::<usize>(input, word).unwrap_err();
+    assert_eq!((2, 3), (e.line, e.column));
+    assert_eq!("x4", e.text);
+    assert_eq!(
+        "line 2, column 3: can't parse 'x4' as usize\n  3 x4\n    ^^",
+        e.to_string()
+    );
+
+    assert_eq!(Ok(6), parse_at::<usize>(input, &input[11..]));
+
+    let e = ParseError::at(input, "elsewhere", "unknown");
+    assert_eq!(0, e.line);
+    assert_eq!("unknown", e.to_string());
+
+    // the same text in another string has no position in input
+    let input = "äbc";
+    let other = String::from(input);
+    assert_eq!(0, ParseError::at(input, &other[2..], "elsewhere").line);
+    let e = ParseError::at(input, &input[2..], "inside");
+    assert_eq!((1, 2), (e.line, e.column));
+}
@@ -0,0 +1,449 @@
+use std::{
+    collections::HashMap,
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Direction, Direction8, ParseError, Vec2};
+
+// positions of the marker chars found while parsing
+type Markers = HashMap<char, Vec<Vec2>>;
+
+#[derive(Clone)]
+pub struct Grid<T> {
+    pub width: i32,
+    pub height: i32,
+    pub data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn for_each_field<F: Fn(Vec2, char)>(input: &str, func: F) {
+        for (y, l) in input.lines().enumerate() {
+            for (x, c) in l.chars().enumerate() {
+                func(
+                    Vec2 {
+                        x: x as i32,
+                        y: y as i32,
+                    },
+                    c,
+                );
+            }
+        }
+    }
+
+    pub fn new(width: i32, height: i32, data: Vec<T>) -> Self {
+        Grid {
+            width,
+            height,
+            data,
+        }
+    }
+
+    // fills the cells row by row
+    pub fn from_fn(width: i32, height: i32, mut f: impl FnMut(Vec2) -> T) -> Self {
+        let mut data = Vec::with_capacity((width * height).max(0) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                data.push(f(Vec2::new(x, y)));
+            }
+        }
+        Grid::new(width, height, data)
+    }
+
+    pub fn index_for(&self, pos: &Vec2) -> Option<usize> {
+        if pos.x >= 0 && pos.x < self.width && pos.y >= 0 && pos.y < self.height {
+            let index = pos.y * self.width + pos.x;
+            return Some(index as usize);
+        }
+        None
+    }
+
+    pub fn contains(&self, pos: &Vec2) -> bool {
+        self.index_for(pos).is_some()
+    }
+
+    pub fn value_for(&self, pos: &Vec2) -> Option<&T> {
+        if let Some(index) = self.index_for(pos) {
+            return Some(&self.data[index]);
+        }
+        None
+    }
+
+    pub fn value_for_checked(&self, pos: &Vec2) -> &T {
+        if let Some(index) = self.index_for(pos) {
+            return &self.data[index];
+        }
+        panic!("check position first via contains");
+    }
+
+    pub fn set_value_for(&mut self, pos: &Vec2, value: T) {
+        if let Some(index) = self.index_for(pos) {
+            self.data[index] = value;
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: &Vec2) -> Option<&mut T> {
+        let index = self.index_for(pos)?;
+        Some(&mut self.data[index])
+    }
+
+    fn position_for(&self, index: usize) -> Vec2 {
+        let index = index as i32;
+        Vec2::new(index % self.width, index / self.width)
+    }
+
+    // panics if one of the positions is outside of the grid
+    pub fn swap(&mut self, a: &Vec2, b: &Vec2) {
+        let a = self.index_for(a).expect("position inside of the grid");
+        let b = self.index_for(b).expect("position inside of the grid");
+        self.data.swap(a, b);
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.data.fill(value);
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.data.iter().map(f).collect())
+    }
+
+    // row by row
+    pub fn iter_positions(&self) -> impl Iterator<Item = (Vec2, &T)> + '_ {
+        self.data
+            .iter()
+            .enumerate()
+            .map(|(i, value)| (self.position_for(i), value))
+    }
+
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Vec2> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter_positions()
+            .filter(move |(_, v)| *v == value)
+            .map(|(pos, _)| pos)
+    }
+
+    // the cells at pos + offset, out of bounds ones are skipped
+    pub fn neighbors_with<'a, I>(
+        &'a self,
+        pos: &Vec2,
+        offsets: I,
+    ) -> impl Iterator<Item = (Vec2, &'a T)> + 'a
+    where
+        I: IntoIterator<Item = Vec2>,
+        I::IntoIter: 'a,
+    {
+        let pos = *pos;
+        offsets.into_iter().filter_map(move |offset| {
+            let next = pos + offset;
+            self.value_for(&next).map(|value| (next, value))
+        })
+    }
+
+    // up, right, down, left
+    pub fn neighbors4(&self, pos: &Vec2) -> impl Iterator<Item = (Vec2, &T)> + '_ {
+        self.neighbors_with(pos, Direction::ALL.map(Vec2::from))
+    }
+
+    // clockwise including the diagonals, starting up
+    pub fn neighbors8(&self, pos: &Vec2) -> impl Iterator<Item = (Vec2, &T)> + '_ {
+        self.neighbors_with(pos, Direction8::ALL.map(Vec2::from))
+    }
+
+    // the cells from start in steps of step until the edge, start included
+    pub fn ray(&self, start: &Vec2, step: Vec2) -> impl Iterator<Item = (Vec2, &T)> + '_ {
+        // a zero step would never leave the grid
+        let limit = if step == Vec2::default() {
+            1
+        } else {
+            usize::MAX
+        };
+        std::iter::successors(Some(*start), move |&pos| Some(pos + step))
+            .take(limit)
+            .map_while(|pos| self.value_for(&pos).map(|value| (pos, value)))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = (Vec2, &T)> + '_> + '_ {
+        (0..self.height).map(|y| self.ray(&Vec2::new(0, y), Vec2::new(1, 0)))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = (Vec2, &T)> + '_> + '_ {
+        (0..self.width).map(|x| self.ray(&Vec2::new(x, 0), Vec2::new(0, 1)))
+    }
+
+    // top left to bottom right, starting in the bottom left corner
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = (Vec2, &T)> + '_> + '_ {
+        let left = (1..self.height).rev().map(|y| Vec2::new(0, y));
+        let top = (0..self.width).map(|x| Vec2::new(x, 0));
+        left.chain(top)
+            .map(|start| self.ray(&start, Vec2::new(1, 1)))
+    }
+
+    // top right to bottom left, starting in the top left corner
+    pub fn anti_diagonals(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = (Vec2, &T)> + '_> + '_ {
+        let top = (0..self.width).map(|x| Vec2::new(x, 0));
+        let right = (1..self.height).map(|y| Vec2::new(self.width - 1, y));
+        top.chain(right)
+            .map(|start| self.ray(&start, Vec2::new(-1, 1)))
+    }
+
+    // like neighbors_with but leaving the grid on one side enters it on the other
+    pub fn neighbors_wrapping<'a, I>(
+        &'a self,
+        pos: &Vec2,
+        offsets: I,
+    ) -> impl Iterator<Item = (Vec2, &'a T)> + 'a
+    where
+        I: IntoIterator<Item = Vec2>,
+        I::IntoIter: 'a,
+    {
+        let pos = *pos;
+        let size = Vec2::new(self.width, self.height);
+        offsets.into_iter().map(move |offset| {
+            let next = (pos + offset).rem_euclid(size);
+            (next, self.value_for_checked(&next))
+        })
+    }
+}
+
+impl<T> Index<Vec2> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2) -> &Self::Output {
+        match self.index_for(&pos) {
+            Some(index) => &self.data[index],
+            None => panic!("position {} outside of the grid", pos),
+        }
+    }
+}
+
+impl<T> IndexMut<Vec2> for Grid<T> {
+    fn index_mut(&mut self, pos: Vec2) -> &mut Self::Output {
+        match self.index_for(&pos) {
+            Some(index) => &mut self.data[index],
+            None => panic!("position {} outside of the grid", pos),
+        }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: TryFrom<char>,
+    T::Error: fmt::Display,
+{
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with_markers(input, "").map(|(grid, _)| grid)
+    }
+
+    // additionally collects the positions of all chars in `markers`, e.g. "SE" for start and end,
+    // the markers are still converted into cells
+    pub fn parse_with_markers(input: &str, markers: &str) -> Result<(Self, Markers), ParseError> {
+        parse_by(input, markers, T::try_from)
+    }
+
+    // a pattern for find_pattern, `wildcard` matches any cell
+    pub fn parse_pattern(input: &str, wildcard: char) -> Result<Grid<Option<T>>, ParseError> {
+        let convert = |c| match c {
+            c if c == wildcard => Ok(None),
+            c => T::try_from(c).map(Some),
+        };
+        parse_by(input, "", convert).map(|(grid, _)| grid)
+    }
+}
+
+fn parse_by<T, E: fmt::Display>(
+    input: &str,
+    markers: &str,
+    convert: impl Fn(char) -> Result<T, E>,
+) -> Result<(Grid<T>, Markers), ParseError> {
+    let mut lines: Vec<&str> = input.lines().collect();
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+
+    let width = match lines.first() {
+        Some(line) => line.chars().count(),
+        None => return Err(ParseError::new("empty grid")),
+    };
+
+    let mut data = Vec::with_capacity(width * lines.len());
+    let mut positions: Markers = HashMap::new();
+
+    for (y, line) in lines.iter().enumerate() {
+        let count = line.chars().count();
+        if count != width {
+            let message = format!("expected {} columns, found {}", width, count);
+            return Err(ParseError::at(input, line, message));
+        }
+
+        for (x, (i, c)) in line.char_indices().enumerate() {
+            if markers.contains(c) {
+                positions
+                    .entry(c)
+                    .or_default()
+                    .push(Vec2::new(x as i32, y as i32));
+            }
+
+            let cell = convert(c)
+                .map_err(|e| ParseError::at(input, &line[i..i + c.len_utf8()], e.to_string()))?;
+            data.push(cell);
+        }
+    }
+
+    Ok((Grid::new(width as i32, lines.len() as i32, data), positions))
+}
+
+impl<T> std::fmt::Debug for Grid<T>
+where
+    T: std::fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Grid {{ w: {} h: {} }}", self.width, self.height)?;
+        for row in self.rows() {
+            for (_, item) in row {
+                write!(f, "{:?}", item)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn parse() {
+    let (grid, markers) = Grid::<char>::parse_with_markers("#S.\r\n..E\r\n\n", "SE").unwrap();
+    assert_eq!((3, 2), (grid.width, grid.height));
+    assert_eq!(Some(&'E'), grid.value_for(&Vec2::new(2, 1)));
+    assert_eq!(vec![Vec2::new(1, 0)], markers[&'S']);
+    assert_eq!(vec![Vec2::new(2, 1)], markers[&'E']);
+
+    let e = Grid::<char>::parse("###\n##\n").unwrap_err();
+    assert_eq!((2, 1), (e.line, e.column));
+    assert_eq!("expected 3 columns, found 2", e.message);
+
+    assert!(Grid::<char>::parse("").is_err());
+}
+
+#[test]
+fn parse_cells() {
+    #[derive(Debug, PartialEq)]
+    struct Wall(bool);
+
+    impl TryFrom<char> for Wall {
+        type Error = String;
+
+        fn try_from(c: char) -> Result<Self, Self::Error> {
+            match c {
+                '#' => Ok(Wall(true)),
+                '.' => Ok(Wall(false)),
+                _ => Err(format!("unknown field '{}'", c)),
+            }
+        }
+    }
+
+    let grid = Grid::<Wall>::parse("#.\n.#").unwrap();
+    assert_eq!(
+        vec![Wall(true), Wall(false), Wall(false), Wall(true)],
+        grid.data
+    );
+
+    let e = Grid::<Wall>::parse("#.\n.x").unwrap_err();
+    assert_eq!((2, 2), (e.line, e.column));
+    assert_eq!("unknown field 'x'", e.message);
+}
+
+#[test]
+fn neighbors() {
+    let grid = Grid::<char>::parse("abc\ndef\nghi").unwrap();
+
+    let cells =
+        |it: &mut dyn Iterator<Item = (Vec2, &char)>| -> String { it.map(|(_, &c)| c).collect() };
+    assert_eq!("bfhd", cells(&mut grid.neighbors4(&Vec2::new(1, 1))));
+    assert_eq!("bcfihgda", cells(&mut grid.neighbors8(&Vec2::new(1, 1))));
+    assert_eq!("bd", cells(&mut grid.neighbors4(&Vec2::new(0, 0))));
+    assert_eq!("bed", cells(&mut grid.neighbors8(&Vec2::new(0, 0))));
+    assert_eq!(
+        "i",
+        cells(&mut grid.neighbors_with(&Vec2::new(0, 0), [Vec2::new(2, 2), Vec2::new(3, 0)]))
+    );
+
+    let wrapped: Vec<_> = grid
+        .neighbors_wrapping(&Vec2::new(0, 0), Direction::ALL.map(Vec2::from))
+        .collect();
+    assert_eq!(
+        vec![
+            (Vec2::new(0, 2), &'g'),
+            (Vec2::new(1, 0), &'b'),
+            (Vec2::new(0, 1), &'d'),
+            (Vec2::new(2, 0), &'c'),
+        ],
+        wrapped
+    );
+}
+
+#[test]
+fn lines() {
+    let grid = Grid::<char>::parse("abc\ndef").unwrap();
+    let text = |lines: Vec<String>| lines.join(" ");
+    let collect =
+        |it: &mut dyn Iterator<Item = (Vec2, &char)>| -> String { it.map(|(_, &c)| c).collect() };
+
+    assert_eq!(
+        "abc def",
+        text(grid.rows().map(|mut r| collect(&mut r)).collect())
+    );
+    assert_eq!(
+        "ad be cf",
+        text(grid.columns().map(|mut c| collect(&mut c)).collect())
+    );
+    assert_eq!(
+        "d ae bf c",
+        text(grid.diagonals().map(|mut d| collect(&mut d)).collect())
+    );
+    assert_eq!(
+        "a bd ce f",
+        text(grid.anti_diagonals().map(|mut d| collect(&mut d)).collect())
+    );
+
+    assert_eq!(
+        "ec",
+        collect(&mut grid.ray(&Vec2::new(1, 1), Vec2::new(1, -1)))
+    );
+    assert_eq!(
+        "a",
+        collect(&mut grid.ray(&Vec2::new(0, 0), Vec2::new(0, 0)))
+    );
+    assert_eq!(
+        "",
+        collect(&mut grid.ray(&Vec2::new(5, 0), Vec2::new(-1, 0)))
+    );
+}
+
+#[test]
+fn mutation() {
+    let mut grid = Grid::<char>::parse("ab.\n.b.").unwrap();
+    let text = |grid: &Grid<char>| -> String { grid.data.iter().collect() };
+
+    assert_eq!('a', grid[Vec2::new(0, 0)]);
+    grid[Vec2::new(2, 1)] = 'c';
+    *grid.get_mut(&Vec2::new(0, 1)).unwrap() = 'd';
+    assert!(grid.get_mut(&Vec2::new(3, 0)).is_none());
+    grid.swap(&Vec2::new(0, 0), &Vec2::new(2, 0));
+    assert_eq!(".badbc", text(&grid));
+
+    assert_eq!(
+        vec![Vec2::new(1, 0), Vec2::new(1, 1)],
+        grid.positions_of(&'b').collect::<Vec<_>>()
+    );
+    assert_eq!(Some((Vec2::new(2, 1), &'c')), grid.iter_positions().last());
+    assert_eq!(".BADBC", text(&grid.map(|c| c.to_ascii_uppercase())));
+
+    grid.fill('x');
+    assert_eq!("xxxxxx", text(&grid));
+}
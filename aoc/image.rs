@@ -0,0 +1,282 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{Grid, Vec2};
+
+// where frame sequences are written to, without it nothing is written
+pub const FRAMES_DIR_VAR: &str = "AOC_FRAMES";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(40, 180, 60);
+    pub const BLUE: Rgb = Rgb(40, 100, 220);
+    pub const YELLOW: Rgb = Rgb(240, 200, 40);
+}
+
+// one pixel per cell, every pixel is an index into the palette
+#[derive(Clone, Debug)]
+pub struct Image {
+    pixels: Grid<u8>,
+    palette: Vec<Rgb>,
+}
+
+impl Image {
+    // every pixel starts with the first color of the palette
+    pub fn new(size: Vec2, palette: &[Rgb]) -> Self {
+        assert!(
+            !palette.is_empty() && palette.len() <= 256,
+            "a palette holds 1 to 256 colors"
+        );
+        Image {
+            pixels: Grid::from_fn(size.x, size.y, |_| 0),
+            palette: palette.to_vec(),
+        }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, palette: &[Rgb], color: impl Fn(&T) -> u8) -> Self {
+        let mut image = Image::new(Vec2::new(grid.width, grid.height), palette);
+        for (pos, value) in grid.iter_positions() {
+            image.set(&pos, color(value));
+        }
+        image
+    }
+
+    // the points in the second color on the first one, points outside are dropped
+    pub fn from_points(
+        size: Vec2,
+        points: impl IntoIterator<Item = Vec2>,
+        palette: &[Rgb],
+    ) -> Self {
+        let mut image = Image::new(size, palette);
+        for pos in points {
+            image.set(&pos, 1);
+        }
+        image
+    }
+
+    pub fn size(&self) -> Vec2 {
+        Vec2::new(self.pixels.width, self.pixels.height)
+    }
+
+    pub fn set(&mut self, pos: &Vec2, color: u8) {
+        assert!(
+            (color as usize) < self.palette.len(),
+            "color {} outside of the palette",
+            color
+        );
+        self.pixels.set_value_for(pos, color);
+    }
+
+    // every pixel becomes a square of factor * factor pixels
+    pub fn scale(&self, factor: i32) -> Self {
+        Image {
+            pixels: self.pixels.scale(Vec2::broadcast(factor)),
+            palette: self.palette.clone(),
+        }
+    }
+
+    // binary PPM (P6)
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let size = self.size();
+        let mut out = format!("P6\n{} {}\n255\n", size.x, size.y).into_bytes();
+        for &index in &self.pixels.data {
+            let Rgb(r, g, b) = self.palette[index as usize];
+            out.extend([r, g, b]);
+        }
+        out
+    }
+
+    // an 8 bit palette PNG, the pixel data isn't compressed
+    pub fn to_png(&self) -> Vec<u8> {
+        let size = self.size();
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+
+        let mut header = Vec::new();
+        header.extend((size.x as u32).to_be_bytes());
+        header.extend((size.y as u32).to_be_bytes());
+        // bit depth, palette color type, compression, filter, interlace
+        header.extend([8, 3, 0, 0, 0]);
+        chunk(&mut out, b"IHDR", &header);
+
+        let palette: Vec<u8> = self
+            .palette
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b])
+            .collect();
+        chunk(&mut out, b"PLTE", &palette);
+
+        // every row starts with the filter type, 0 is none
+        let mut rows = Vec::with_capacity(self.pixels.data.len() + size.y.max(0) as usize);
+        for row in self.pixels.data.chunks(size.x.max(1) as usize) {
+            rows.push(0);
+            rows.extend(row);
+        }
+        chunk(&mut out, b"IDAT", &zlib_stored(&rows));
+        chunk(&mut out, b"IEND", &[]);
+
+        out
+    }
+
+    // the format follows the extension, .png or .ppm
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let data = match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => self.to_png(),
+            Some("ppm") => self.to_ppm(),
+            _ => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("unknown image format {}", path.display()),
+                ))
+            }
+        };
+        fs::write(path, data)
+    }
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+// length, type, data and the crc of type and data
+fn chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+// a zlib stream made of uncompressed deflate blocks, each holds up to 65535 bytes
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut out = vec![0x78, 0x01];
+
+    let mut blocks = data.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+// numbered images in one directory, e.g. frames/day14/000100.png
+#[derive(Clone, Debug)]
+pub struct Frames {
+    dir: PathBuf,
+    extension: &'static str,
+    next: usize,
+}
+
+impl Frames {
+    // creates the directory, the extension picks the format
+    pub fn new(dir: impl Into<PathBuf>, extension: &'static str) -> io::Result<Self> {
+        if extension != "png" && extension != "ppm" {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unknown image format {}", extension),
+            ));
+        }
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Frames {
+            dir,
+            extension,
+            next: 0,
+        })
+    }
+
+    // PNG frames in AOC_FRAMES/<name> if the variable is set
+    pub fn from_env(name: &str) -> io::Result<Option<Self>> {
+        match env::var_os(FRAMES_DIR_VAR) {
+            Some(dir) => Frames::new(Path::new(&dir).join(name), "png").map(Some),
+            None => Ok(None),
+        }
+    }
+
+    pub fn path(&self, number: usize) -> PathBuf {
+        self.dir.join(format!("{:06}.{}", number, self.extension))
+    }
+
+    // numbered after the last frame written
+    pub fn push(&mut self, image: &Image) -> io::Result<PathBuf> {
+        self.write(self.next, image)
+    }
+
+    pub fn write(&mut self, number: usize, image: &Image) -> io::Result<PathBuf> {
+        let path = self.path(number);
+        image.save(&path)?;
+        self.next = number + 1;
+        Ok(path)
+    }
+}
+
+#[test]
+fn image() {
+    assert_eq!(0xcbf4_3926, crc32(b"123456789"));
+    assert_eq!(0x11e6_0398, adler32(b"Wikipedia"));
+
+    let image = Image::from_points(
+        Vec2::new(2, 2),
+        [Vec2::new(1, 0), Vec2::new(5, 5)],
+        &[Rgb::BLACK, Rgb::WHITE],
+    );
+    let mut ppm = b"P6\n2 2\n255\n".to_vec();
+    ppm.extend([0, 0, 0, 255, 255, 255]);
+    ppm.extend([0; 6]);
+    assert_eq!(ppm, image.to_ppm());
+    assert_eq!(Vec2::new(4, 4), image.scale(2).size());
+
+    let png = image.to_png();
+    assert_eq!(b"\x89PNG\r\n\x1a\n", &png[..8]);
+    assert_eq!(b"IHDR", &png[12..16]);
+    assert_eq!([0, 0, 0, 2, 0, 0, 0, 2, 8, 3], png[16..26]);
+    // two rows of filter byte plus two pixels in a single stored block
+    let idat = png.windows(4).position(|w| w == b"IDAT").unwrap();
+    assert_eq!(
+        [0x78, 0x01, 1, 6, 0, 0xf9, 0xff, 0, 0, 1, 0, 0, 0],
+        png[idat + 4..idat + 17]
+    );
+    assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+
+    let grid = Grid::<char>::parse("#.\n.#").unwrap();
+    let image = Image::from_grid(&grid, &[Rgb::BLACK, Rgb::RED], |&c| (c == '#') as u8);
+    assert_eq!(&[220, 50, 47], &image.to_ppm()[11..14]);
+}
@@ -0,0 +1,91 @@
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+// overrides the directory that contains the dayNN folders
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug)]
+pub struct InputError {
+    pub path: PathBuf,
+    pub source: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.source.kind() == io::ErrorKind::NotFound {
+            write!(
+                f,
+                "missing input file {}, drop it in place or point {} / --input-dir at your inputs",
+                self.path.display(),
+                INPUT_DIR_VAR
+            )
+        } else {
+            write!(f, "can't read {}: {}", self.path.display(), self.source)
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+// resolves dayNN/<variant>.txt below a root directory,
+// the puzzle input is the variant "input", examples are "test", "test1", ...
+#[derive(Clone, Debug)]
+pub struct Inputs {
+    root: PathBuf,
+}
+
+impl Inputs {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Inputs { root: root.into() }
+    }
+
+    // AOC_INPUT_DIR if set, otherwise the current directory
+    pub fn from_env() -> Self {
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dir) => Inputs::new(dir),
+            None => Inputs::new("."),
+        }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn day_dir(&self, day: u8) -> PathBuf {
+        self.root.join(format!("day{:02}", day))
+    }
+
+    pub fn path(&self, day: u8, variant: &str) -> PathBuf {
+        self.day_dir(day).join(format!("{}.txt", variant))
+    }
+
+    pub fn load(&self, day: u8, variant: &str) -> Result<String, InputError> {
+        let path = self.path(day, variant);
+        fs::read_to_string(&path).map_err(|source| InputError { path, source })
+    }
+
+    // all variants found for a day, sorted by name
+    pub fn variants(&self, day: u8) -> Vec<String> {
+        let mut variants: Vec<String> = fs::read_dir(self.day_dir(day))
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|e| e == "txt"))
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+            .collect();
+
+        variants.sort();
+        variants
+    }
+}
+
+pub fn load_input(day: u8, variant: &str) -> Result<String, InputError> {
+    Inputs::from_env().load(day, variant)
+}
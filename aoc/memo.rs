@@ -0,0 +1,105 @@
+use std::{collections::HashMap, hash::Hash};
+
+// the function gets a handle to call itself through the cache, e.g.
+// memoize(|fib, n: u64| if n < 2 { n } else { fib(n - 1) + fib(n - 2) })
+pub struct Memo<K, V, F> {
+    f: F,
+    cache: HashMap<K, V>,
+    hits: usize,
+    misses: usize,
+}
+
+pub fn memoize<K, V, F>(f: F) -> Memo<K, V, F>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    Memo {
+        f,
+        cache: HashMap::new(),
+        hits: 0,
+        misses: 0,
+    }
+}
+
+impl<K, V, F> Memo<K, V, F>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    pub fn get(&mut self, key: K) -> V {
+        lookup(
+            &self.f,
+            &mut self.cache,
+            &mut self.hits,
+            &mut self.misses,
+            key,
+        )
+    }
+
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    // the number of cached results
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+// split from Memo so the function can be borrowed next to the cache
+fn lookup<K, V, F>(
+    f: &F,
+    cache: &mut HashMap<K, V>,
+    hits: &mut usize,
+    misses: &mut usize,
+    key: K,
+) -> V
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+    F: Fn(&mut dyn FnMut(K) -> V, K) -> V,
+{
+    if let Some(value) = cache.get(&key) {
+        *hits += 1;
+        return value.clone();
+    }
+
+    *misses += 1;
+    let value = f(&mut |key| lookup(f, cache, hits, misses, key), key.clone());
+    cache.insert(key, value.clone());
+    value
+}
+
+#[test]
+fn memoize_fib() {
+    let mut fib = memoize(|fib, n: u64| if n < 2 { n } else { fib(n - 1) + fib(n - 2) });
+    assert_eq!(2_880_067_194_370_816_120, fib.get(90));
+    // every n once, the second branch finds its value cached from 3 on
+    assert_eq!((91, 88), (fib.misses(), fib.hits()));
+    assert_eq!(91, fib.len());
+
+    assert_eq!(12_586_269_025, fib.get(50));
+    assert_eq!((91, 89), (fib.misses(), fib.hits()));
+
+    fib.clear();
+    assert!(fib.is_empty());
+    assert_eq!(55, fib.get(10));
+    assert_eq!(11, fib.misses());
+}
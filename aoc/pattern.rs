@@ -0,0 +1,138 @@
+use crate::{Grid, Vec2};
+
+// which variants of a pattern find_pattern looks for
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Orientations {
+    Fixed,
+    Rotations,
+    RotationsAndReflections,
+}
+
+// a pattern turned into the offsets of its non wildcard cells from the top left corner
+struct Variant<'a, T> {
+    size: Vec2,
+    cells: Vec<(Vec2, &'a T)>,
+}
+
+fn variants<T: PartialEq>(
+    pattern: &Grid<Option<T>>,
+    orientations: Orientations,
+) -> Vec<Variant<'_, T>> {
+    let transforms: &[fn(Vec2) -> Vec2] = match orientations {
+        Orientations::Fixed => &[|v| v],
+        Orientations::Rotations => &[|v| v, |v| v.rotate_right(), |v| -v, |v| v.rotate_left()],
+        Orientations::RotationsAndReflections => &[
+            |v| v,
+            |v| v.rotate_right(),
+            |v| -v,
+            |v| v.rotate_left(),
+            |v| Vec2::new(-v.x, v.y),
+            |v| Vec2::new(-v.x, v.y).rotate_right(),
+            |v| Vec2::new(v.x, -v.y),
+            |v| Vec2::new(-v.x, v.y).rotate_left(),
+        ],
+    };
+
+    let corners = [
+        Vec2::new(0, 0),
+        Vec2::new(pattern.width - 1, pattern.height - 1),
+    ];
+
+    let mut result: Vec<Variant<T>> = Vec::new();
+    for transform in transforms {
+        let a = transform(corners[0]);
+        let b = transform(corners[1]);
+        let min = a.min(b);
+
+        let mut cells: Vec<(Vec2, &T)> = Vec::new();
+        for y in 0..pattern.height {
+            for x in 0..pattern.width {
+                let pos = Vec2::new(x, y);
+                if let Some(value) = pattern.value_for_checked(&pos) {
+                    cells.push((transform(pos) - min, value));
+                }
+            }
+        }
+        cells.sort_by_key(|(offset, _)| (offset.y, offset.x));
+
+        // symmetric patterns map onto themselves, every match is only reported once
+        if !result.iter().any(|v| v.cells == cells) {
+            result.push(Variant {
+                size: (a - b).abs() + Vec2::broadcast(1),
+                cells,
+            });
+        }
+    }
+
+    result
+}
+
+impl<T: PartialEq> Grid<T> {
+    // the top left corners of all places the pattern matches, sorted top to bottom,
+    // a position shows up once per distinct orientation matching there
+    pub fn find_pattern(&self, pattern: &Grid<Option<T>>, orientations: Orientations) -> Vec<Vec2> {
+        let mut matches = Vec::new();
+
+        for variant in variants(pattern, orientations) {
+            for y in 0..=self.height - variant.size.y {
+                for x in 0..=self.width - variant.size.x {
+                    let pos = Vec2::new(x, y);
+                    let found = variant
+                        .cells
+                        .iter()
+                        .all(|&(offset, value)| self.value_for(&(pos + offset)) == Some(value));
+                    if found {
+                        matches.push(pos);
+                    }
+                }
+            }
+        }
+
+        matches.sort_by_key(|pos| (pos.y, pos.x));
+        matches
+    }
+}
+
+#[test]
+fn find_pattern() {
+    // XMAS
+    // AMXS
+    // SAMX
+    let grid = Grid::<char>::parse("XMAS\nAMXS\nSAMX").unwrap();
+
+    let xmas = Grid::<char>::parse_pattern("XMAS", '.').unwrap();
+    assert_eq!(
+        vec![Vec2::new(0, 0)],
+        grid.find_pattern(&xmas, Orientations::Fixed)
+    );
+    assert_eq!(
+        vec![Vec2::new(0, 0), Vec2::new(0, 2)],
+        grid.find_pattern(&xmas, Orientations::Rotations)
+    );
+
+    // only the rotated .S/X. is in the grid
+    let corner = Grid::<char>::parse_pattern("X.\n.S", '.').unwrap();
+    assert!(grid.find_pattern(&corner, Orientations::Fixed).is_empty());
+    assert_eq!(
+        vec![Vec2::new(2, 0)],
+        grid.find_pattern(&corner, Orientations::Rotations)
+    );
+
+    // MX/AM holds the L shape and its mirror image
+    let l = Grid::<char>::parse_pattern("AM\n.X", '.').unwrap();
+    assert_eq!(
+        vec![Vec2::new(1, 1)],
+        grid.find_pattern(&l, Orientations::Rotations)
+    );
+    assert_eq!(
+        vec![Vec2::new(1, 1), Vec2::new(1, 1)],
+        grid.find_pattern(&l, Orientations::RotationsAndReflections)
+    );
+
+    // a symmetric pattern matches once per position
+    let a = Grid::<char>::parse_pattern("A", '.').unwrap();
+    assert_eq!(
+        vec![Vec2::new(2, 0), Vec2::new(0, 1), Vec2::new(1, 2)],
+        grid.find_pattern(&a, Orientations::RotationsAndReflections)
+    );
+}
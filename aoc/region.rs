@@ -0,0 +1,205 @@
+use std::collections::HashSet;
+
+use crate::{Direction, Grid, Vec2};
+
+// a set of cells connected through their four sides
+#[derive(Clone, Debug)]
+pub struct Region {
+    // row by row
+    cells: Vec<Vec2>,
+    lookup: HashSet<Vec2>,
+}
+
+// the cells reachable from start, joined decides if a step between two cells is possible
+fn flood(start: Vec2, seen: &mut HashSet<Vec2>, joined: impl Fn(&Vec2, &Vec2) -> bool) -> Region {
+    seen.insert(start);
+    let mut cells = vec![start];
+
+    // iterative, a big region doesn't grow the stack
+    let mut next_cell = 0;
+    while let Some(&pos) = cells.get(next_cell) {
+        next_cell += 1;
+        for dir in Direction::ALL {
+            let next = pos + Vec2::from(dir);
+            if joined(&pos, &next) && seen.insert(next) {
+                cells.push(next);
+            }
+        }
+    }
+
+    Region::new(cells)
+}
+
+impl Region {
+    fn new(mut cells: Vec<Vec2>) -> Self {
+        cells.sort_by_key(|pos| (pos.y, pos.x));
+        let lookup = cells.iter().copied().collect();
+        Region { cells, lookup }
+    }
+
+    pub fn cells(&self) -> &[Vec2] {
+        &self.cells
+    }
+
+    pub fn contains(&self, pos: &Vec2) -> bool {
+        self.lookup.contains(pos)
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    // the number of cell sides facing something else
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .flat_map(|&pos| Direction::ALL.map(|dir| pos + Vec2::from(dir)))
+            .filter(|next| !self.contains(next))
+            .count()
+    }
+
+    // outer corners plus inner corners, the outline of holes included
+    pub fn corners(&self) -> usize {
+        let mut corners = 0;
+        for &pos in &self.cells {
+            for dir in Direction::ALL {
+                let a = pos + Vec2::from(dir);
+                let b = pos + Vec2::from(dir.turn_right());
+                let diagonal = a + Vec2::from(dir.turn_right());
+
+                let (a, b) = (self.contains(&a), self.contains(&b));
+                if (!a && !b) || (a && b && !self.contains(&diagonal)) {
+                    corners += 1;
+                }
+            }
+        }
+        corners
+    }
+
+    // every side of the fence ends in a corner
+    pub fn sides(&self) -> usize {
+        self.corners()
+    }
+
+    // min and max corner, both inclusive
+    pub fn bounds(&self) -> (Vec2, Vec2) {
+        let first = self.cells[0];
+        self.cells.iter().fold((first, first), |(min, max), &pos| {
+            (min.min(pos), max.max(pos))
+        })
+    }
+
+    // the cells with at least one side facing something else, row by row
+    pub fn boundary(&self) -> Vec<Vec2> {
+        self.cells
+            .iter()
+            .copied()
+            .filter(|&pos| {
+                Direction::ALL
+                    .iter()
+                    .any(|&dir| !self.contains(&(pos + Vec2::from(dir))))
+            })
+            .collect()
+    }
+
+    // the areas enclosed by the region that can't reach the outside
+    pub fn holes(&self) -> Vec<Region> {
+        let (min, max) = self.bounds();
+        let (min, max) = (min - Vec2::broadcast(1), max + Vec2::broadcast(1));
+        let inside = |pos: &Vec2| pos.min(min) == min && pos.max(max) == max;
+
+        // the padded corner is always outside of the region
+        let mut outside = HashSet::new();
+        flood(min, &mut outside, |_, next| {
+            inside(next) && !self.contains(next)
+        });
+
+        let mut seen = outside;
+        let mut holes = Vec::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                let pos = Vec2::new(x, y);
+                if !self.contains(&pos) && !seen.contains(&pos) {
+                    holes.push(flood(pos, &mut seen, |_, next| !self.contains(next)));
+                }
+            }
+        }
+        holes
+    }
+}
+
+impl<T> Grid<T> {
+    // neighboring cells with equal values, row by row of their first cell
+    pub fn regions(&self) -> Vec<Region>
+    where
+        T: PartialEq,
+    {
+        self.regions_by(|a, b| a == b)
+    }
+
+    // neighboring cells end up in the same region when same(a, b) holds
+    pub fn regions_by(&self, same: impl Fn(&T, &T) -> bool) -> Vec<Region> {
+        self.label(|_| true, same)
+    }
+
+    // the connected groups of cells passing keep, the rest isn't part of any region
+    pub fn regions_where(&self, keep: impl Fn(&T) -> bool) -> Vec<Region> {
+        self.label(&keep, |_, b| keep(b))
+    }
+
+    fn label(&self, keep: impl Fn(&T) -> bool, same: impl Fn(&T, &T) -> bool) -> Vec<Region> {
+        let mut seen = HashSet::new();
+        let mut regions = Vec::new();
+
+        for (pos, value) in self.iter_positions() {
+            if keep(value) && !seen.contains(&pos) {
+                regions.push(flood(pos, &mut seen, |from, to| {
+                    self.value_for(to)
+                        .is_some_and(|next| same(&self[*from], next))
+                }));
+            }
+        }
+
+        regions
+    }
+}
+
+#[test]
+fn regions() {
+    let grid = Grid::<char>::parse("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+    let regions = grid.regions();
+    let stats: Vec<_> = regions
+        .iter()
+        .map(|r| (grid[r.cells()[0]], r.area(), r.perimeter(), r.sides()))
+        .collect();
+    assert_eq!(
+        vec![
+            ('A', 4, 10, 4),
+            ('B', 4, 8, 4),
+            ('C', 4, 10, 8),
+            ('D', 1, 4, 4),
+            ('E', 3, 8, 4)
+        ],
+        stats
+    );
+    assert_eq!((Vec2::new(2, 1), Vec2::new(3, 3)), regions[2].bounds());
+    assert!(regions[2].holes().is_empty());
+
+    let grid = Grid::<char>::parse("OOOOO\nOXOXO\nOOOOO\nOXOXO\nOOOOO").unwrap();
+    let outer = &grid.regions()[0];
+    assert_eq!(
+        (21, 36, 20),
+        (outer.area(), outer.perimeter(), outer.sides())
+    );
+    assert_eq!(20, outer.boundary().len());
+    assert!(!outer.boundary().contains(&Vec2::new(2, 2)));
+
+    let holes = outer.holes();
+    assert_eq!(4, holes.len());
+    assert_eq!(vec![Vec2::new(3, 1)], holes[1].cells());
+    assert_eq!(4, grid.regions_where(|&c| c == 'X').len());
+
+    // numbers joined when they differ by at most one
+    let grid = Grid::new(4, 1, vec![1, 2, 4, 5]);
+    assert_eq!(2, grid.regions_by(|a: &i32, b| (a - b).abs() <= 1).len());
+}
@@ -0,0 +1,239 @@
+use std::{fmt, io, path::Path};
+
+use crate::{Grid, Vec2};
+
+// the character a cell is drawn with
+pub trait CellGlyph {
+    fn glyph(&self) -> char;
+}
+
+impl CellGlyph for char {
+    fn glyph(&self) -> char {
+        *self
+    }
+}
+
+impl CellGlyph for bool {
+    fn glyph(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+// empty cells are drawn as '.'
+impl<T: CellGlyph> CellGlyph for Option<T> {
+    fn glyph(&self) -> char {
+        self.as_ref().map_or('.', T::glyph)
+    }
+}
+
+// a single digit, larger numbers don't fit and become '+'
+macro_rules! digit_glyph {
+    ($($t:ty)*) => {$(
+        impl CellGlyph for $t {
+            fn glyph(&self) -> char {
+                u32::try_from(*self)
+                    .ok()
+                    .and_then(|d| char::from_digit(d, 10))
+                    .unwrap_or('+')
+            }
+        }
+    )*};
+}
+
+digit_glyph!(u8 u16 u32 u64 usize i32 i64);
+
+// ANSI terminal colors
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Color {
+    pub const ALL: [Color; 7] = [
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::White,
+    ];
+
+    // a color per index, repeating after seven, e.g. for region ids
+    pub fn cycle(index: usize) -> Self {
+        Color::ALL[index % Color::ALL.len()]
+    }
+
+    fn code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+        }
+    }
+}
+
+// the glyphs of a grid plus overlays, later overlays draw over earlier ones
+pub struct Render<'a, T> {
+    grid: &'a Grid<T>,
+    glyphs: Vec<char>,
+    colors: Vec<Option<Color>>,
+}
+
+impl<T: CellGlyph> Grid<T> {
+    pub fn render(&self) -> Render<'_, T> {
+        self.render_with(T::glyph)
+    }
+}
+
+impl<T: CellGlyph> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.render().fmt(f)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn render_with(&self, glyph: impl Fn(&T) -> char) -> Render<'_, T> {
+        Render {
+            grid: self,
+            glyphs: self.data.iter().map(glyph).collect(),
+            colors: vec![None; self.data.len()],
+        }
+    }
+}
+
+// the arrow pointing from one position towards the next
+fn arrow(step: Vec2) -> char {
+    match (step.x.signum(), step.y.signum()) {
+        (1, 0) => '>',
+        (-1, 0) => '<',
+        (0, 1) => 'v',
+        (0, -1) => '^',
+        (1, 1) | (-1, -1) => '\\',
+        (1, -1) | (-1, 1) => '/',
+        _ => '*',
+    }
+}
+
+impl<T> Render<'_, T> {
+    // positions outside of the grid are skipped
+    pub fn highlight(mut self, positions: impl IntoIterator<Item = Vec2>, glyph: char) -> Self {
+        for pos in positions {
+            if let Some(index) = self.grid.index_for(&pos) {
+                self.glyphs[index] = glyph;
+            }
+        }
+        self
+    }
+
+    // every step is drawn as an arrow towards the next one, the last keeps its direction
+    pub fn path(mut self, positions: impl IntoIterator<Item = Vec2>) -> Self {
+        let positions: Vec<Vec2> = positions.into_iter().collect();
+        for (i, pos) in positions.iter().enumerate() {
+            let glyph = match (positions.get(i + 1), i.checked_sub(1)) {
+                (Some(next), _) => arrow(*next - *pos),
+                (None, Some(prev)) => arrow(*pos - positions[prev]),
+                (None, None) => '*',
+            };
+            if let Some(index) = self.grid.index_for(pos) {
+                self.glyphs[index] = glyph;
+            }
+        }
+        self
+    }
+
+    pub fn color(mut self, positions: impl IntoIterator<Item = Vec2>, color: Color) -> Self {
+        for pos in positions {
+            if let Some(index) = self.grid.index_for(&pos) {
+                self.colors[index] = Some(color);
+            }
+        }
+        self
+    }
+
+    // e.g. one color per region or plant type, None leaves the cell as it is
+    pub fn color_by(mut self, f: impl Fn(Vec2, &T) -> Option<Color>) -> Self {
+        for (i, (pos, value)) in self.grid.iter_positions().enumerate() {
+            if let Some(color) = f(pos, value) {
+                self.colors[i] = Some(color);
+            }
+        }
+        self
+    }
+
+    pub fn print(&self) {
+        print!("{}", self);
+    }
+
+    pub fn write(&self, out: &mut impl io::Write) -> io::Result<()> {
+        write!(out, "{}", self)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_string())
+    }
+}
+
+impl<T> fmt::Display for Render<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.grid.width.max(1) as usize;
+        for (glyphs, colors) in self.glyphs.chunks(width).zip(self.colors.chunks(width)) {
+            for (glyph, color) in glyphs.iter().zip(colors) {
+                match color {
+                    Some(color) => write!(f, "\x1b[{}m{}\x1b[0m", color.code(), glyph)?,
+                    None => write!(f, "{}", glyph)?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn render() {
+    let grid = Grid::<char>::parse("#..\n...\n..#").unwrap();
+    assert_eq!("#..\n...\n..#\n", grid.to_string());
+
+    let path = [
+        Vec2::new(0, 1),
+        Vec2::new(1, 1),
+        Vec2::new(1, 2),
+        Vec2::new(1, 3),
+    ];
+    let text = grid
+        .render()
+        .path(path)
+        .highlight([Vec2::new(2, 0)], 'X')
+        .to_string();
+    assert_eq!("#.X\n>v.\n.v#\n", text);
+
+    let colored = grid
+        .render()
+        .color_by(|_, &c| (c == '#').then_some(Color::Red))
+        .to_string();
+    assert_eq!("\x1b[31m#\x1b[0m..\n...\n..\x1b[31m#\x1b[0m\n", colored);
+
+    let heights = Grid::new(3, 1, vec![Some(4usize), None, Some(12)]);
+    assert_eq!("4.+\n", heights.to_string());
+    assert_eq!(
+        "yny\n",
+        heights
+            .render_with(|h| if h.is_some() { 'y' } else { 'n' })
+            .to_string()
+    );
+}
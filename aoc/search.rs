@@ -0,0 +1,510 @@
+use std::{
+    cell::OnceCell,
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+use crate::Int;
+
+// what a search found, states it never reached have no distance
+#[derive(Clone, Debug)]
+pub struct Search<S, C = usize> {
+    pub distances: HashMap<S, C>,
+    // the state every reached state was first entered from, starts have none
+    pub predecessors: HashMap<S, S>,
+    // the goal the search stopped at
+    pub goal: Option<S>,
+}
+
+impl<S: Hash + Eq + Clone, C: Copy> Search<S, C> {
+    fn new() -> Self {
+        Search {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn goal_distance(&self) -> Option<C> {
+        self.distance(self.goal.as_ref()?)
+    }
+
+    // from a start to the state, both included
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        if !self.distances.contains_key(state) {
+            return None;
+        }
+
+        let mut path = vec![state.clone()];
+        while let Some(prev) = self.predecessors.get(path.last().unwrap()) {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn goal_path(&self) -> Option<Vec<S>> {
+        self.path(self.goal.as_ref()?)
+    }
+}
+
+// fewest steps, stops at the first state passing is_goal
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.distances.contains_key(&start) {
+            search.distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(state) = queue.pop_front() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        let distance = search.distances[&state];
+        for next in successors(&state) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), distance + 1);
+                search.predecessors.insert(next.clone(), state.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search
+}
+
+// depth first, the distances are the depths at which the states were found
+pub fn dfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut stack = Vec::new();
+    for start in starts {
+        if !search.distances.contains_key(&start) {
+            search.distances.insert(start.clone(), 0);
+            stack.push(start);
+        }
+    }
+
+    while let Some(state) = stack.pop() {
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        let depth = search.distances[&state];
+        for next in successors(&state) {
+            if !search.distances.contains_key(&next) {
+                search.distances.insert(next.clone(), depth + 1);
+                search.predecessors.insert(next.clone(), state.clone());
+                stack.push(next);
+            }
+        }
+    }
+
+    search
+}
+
+// cheapest paths, successors yield the next state and the cost to get there
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Int,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(starts, successors, |_| C::ZERO, is_goal)
+}
+
+// dijkstra guided by a heuristic that must never overestimate the remaining cost
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Int,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut search = Search::new();
+
+    // the heap only orders by cost, the states themselves live in `queued`
+    let mut queued: Vec<(S, C)> = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if !search.distances.contains_key(&start) {
+            search.distances.insert(start.clone(), C::ZERO);
+            heap.push(Reverse((heuristic(&start), queued.len())));
+            queued.push((start, C::ZERO));
+        }
+    }
+
+    while let Some(Reverse((_, index))) = heap.pop() {
+        let (state, cost) = queued[index].clone();
+        // a cheaper way was found after this one was queued
+        if search.distances[&state] < cost {
+            continue;
+        }
+
+        if is_goal(&state) {
+            search.goal = Some(state);
+            break;
+        }
+
+        for (next, step) in successors(&state) {
+            let next_cost = cost + step;
+            if search.distances.get(&next).is_none_or(|&d| next_cost < d) {
+                search.distances.insert(next.clone(), next_cost);
+                search.predecessors.insert(next.clone(), state.clone());
+                heap.push(Reverse((next_cost + heuristic(&next), queued.len())));
+                queued.push((next, next_cost));
+            }
+        }
+    }
+
+    search
+}
+
+// like Search but keeping every optimal predecessor of a state,
+// only states settled before the search stopped are kept
+#[derive(Clone, Debug)]
+pub struct AllPaths<S, C = usize> {
+    pub distances: HashMap<S, C>,
+    pub predecessors: HashMap<S, Vec<S>>,
+    // all goals reached at the lowest cost
+    pub goals: Vec<S>,
+    // filled by the first path_counts call
+    counts: OnceCell<HashMap<S, usize>>,
+}
+
+impl<S: Hash + Eq + Clone, C: Copy + Ord> AllPaths<S, C> {
+    pub fn distance(&self, state: &S) -> Option<C> {
+        self.distances.get(state).copied()
+    }
+
+    pub fn goal_distance(&self) -> Option<C> {
+        self.distance(self.goals.first()?)
+    }
+
+    // the number of optimal paths from any start to every reached state,
+    // only counted once
+    pub fn path_counts(&self) -> &HashMap<S, usize> {
+        self.counts.get_or_init(|| {
+            let mut states: Vec<&S> = self.distances.keys().collect();
+            states.sort_by_key(|&state| self.distances[state]);
+
+            // the predecessors are always closer, so they are counted first
+            let mut counts: HashMap<S, usize> = HashMap::new();
+            for state in states {
+                let count = match self.predecessors.get(state) {
+                    Some(prev) if !prev.is_empty() => prev.iter().map(|p| counts[p]).sum(),
+                    _ => 1,
+                };
+                counts.insert(state.clone(), count);
+            }
+            counts
+        })
+    }
+
+    pub fn count_paths(&self, state: &S) -> usize {
+        self.path_counts().get(state).copied().unwrap_or(0)
+    }
+
+    // every optimal path from a start to the state, one at a time
+    pub fn paths<'a>(&'a self, state: &S) -> impl Iterator<Item = Vec<S>> + 'a {
+        // partial paths walking backwards from the state
+        let mut stack = Vec::new();
+        if self.distances.contains_key(state) {
+            stack.push(vec![state.clone()]);
+        }
+
+        std::iter::from_fn(move || {
+            while let Some(mut path) = stack.pop() {
+                match self.predecessors.get(path.last().unwrap()) {
+                    Some(prev) if !prev.is_empty() => {
+                        for p in prev.iter().rev() {
+                            let mut longer = path.clone();
+                            longer.push(p.clone());
+                            stack.push(longer);
+                        }
+                    }
+                    _ => {
+                        path.reverse();
+                        return Some(path);
+                    }
+                }
+            }
+            None
+        })
+    }
+
+    // the states on any optimal path to one of the targets, the targets included
+    pub fn on_paths(&self, targets: &[S]) -> HashSet<S> {
+        let mut seen: HashSet<S> = HashSet::new();
+        let mut todo: Vec<S> = targets
+            .iter()
+            .filter(|&t| self.distances.contains_key(t))
+            .cloned()
+            .collect();
+        while let Some(state) = todo.pop() {
+            if seen.insert(state.clone()) {
+                todo.extend(self.predecessors.get(&state).into_iter().flatten().cloned());
+            }
+        }
+        seen
+    }
+}
+
+// dijkstra_all with unit steps
+pub fn bfs_all<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> AllPaths<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    dijkstra_all(
+        starts,
+        |state| successors(state).into_iter().map(|next| (next, 1)),
+        is_goal,
+    )
+}
+
+// dijkstra that keeps searching until every goal at the lowest cost is found,
+// the step costs have to be positive
+pub fn dijkstra_all<S, C, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> AllPaths<S, C>
+where
+    S: Hash + Eq + Clone,
+    C: Int,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut paths = AllPaths {
+        distances: HashMap::new(),
+        predecessors: HashMap::new(),
+        goals: Vec::new(),
+        counts: OnceCell::new(),
+    };
+
+    let mut queued: Vec<S> = Vec::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if !paths.distances.contains_key(&start) {
+            paths.distances.insert(start.clone(), C::ZERO);
+            heap.push(Reverse((C::ZERO, queued.len())));
+            queued.push(start);
+        }
+    }
+
+    // states whose distance and predecessors are final
+    let mut settled: HashSet<S> = HashSet::new();
+    let mut best = None;
+    while let Some(Reverse((cost, index))) = heap.pop() {
+        let state = queued[index].clone();
+        if paths.distances[&state] < cost {
+            continue;
+        }
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        settled.insert(state.clone());
+
+        if is_goal(&state) {
+            best = Some(cost);
+            paths.goals.push(state);
+            continue;
+        }
+
+        for (next, step) in successors(&state) {
+            // with a free step a predecessor could share the distance of its successor
+            assert!(step > C::ZERO, "dijkstra_all needs positive step costs");
+            let next_cost = cost + step;
+            match paths.distances.get(&next) {
+                Some(&d) if next_cost > d => {}
+                Some(&d) if next_cost == d => {
+                    paths
+                        .predecessors
+                        .entry(next)
+                        .or_default()
+                        .push(state.clone());
+                }
+                _ => {
+                    paths.distances.insert(next.clone(), next_cost);
+                    paths.predecessors.insert(next.clone(), vec![state.clone()]);
+                    heap.push(Reverse((next_cost, queued.len())));
+                    queued.push(next);
+                }
+            }
+        }
+    }
+
+    // the states still queued only have tentative distances and predecessors
+    paths.distances.retain(|state, _| settled.contains(state));
+    paths
+        .predecessors
+        .retain(|state, _| settled.contains(state));
+    paths
+}
+
+#[test]
+fn search() {
+    use crate::{Grid, Vec2};
+
+    let grid = Grid::<char>::parse("S..#\n.#..\n...E").unwrap();
+    let end = Vec2::new(3, 2);
+    let open = |pos: &Vec2| {
+        grid.neighbors4(pos)
+            .filter(|&(_, &c)| c != '#')
+            .map(|(next, _)| next)
+            .collect::<Vec<_>>()
+    };
+
+    let found = bfs([Vec2::new(0, 0)], open, |&pos| pos == end);
+    assert_eq!(Some(5), found.goal_distance());
+    let path = found.goal_path().unwrap();
+    assert_eq!(6, path.len());
+    assert_eq!((Vec2::new(0, 0), end), (path[0], path[5]));
+
+    let all = bfs([Vec2::new(0, 0)], open, |_| false);
+    assert_eq!(None, all.goal);
+    assert_eq!(10, all.distances.len());
+    assert_eq!(Some(4), all.distance(&Vec2::new(3, 1)));
+
+    let deep = dfs([Vec2::new(0, 0)], open, |&pos| pos == end);
+    assert!(deep.goal_distance().unwrap() >= 5);
+    assert_eq!(Some(end), deep.goal_path().unwrap().last().copied());
+
+    // walking right is cheap, down is expensive
+    let weighted = |&pos: &Vec2| {
+        open(&pos).into_iter().map(move |next| {
+            let cost = if next.y > pos.y { 10 } else { 1 };
+            (next, cost)
+        })
+    };
+    let cheap = dijkstra([Vec2::new(0, 0)], weighted, |&pos| pos == end);
+    assert_eq!(Some(23), cheap.goal_distance());
+
+    let guided = astar(
+        [Vec2::new(0, 0)],
+        weighted,
+        |pos| pos.manhattan(end),
+        |&pos| pos == end,
+    );
+    assert_eq!(cheap.goal_distance(), guided.goal_distance());
+    assert_eq!(
+        None,
+        dijkstra(
+            [0],
+            |&n| [(n + 1, 1)].into_iter().filter(|&(n, _)| n < 5),
+            |&n| n == 9
+        )
+        .goal
+    );
+}
+
+#[test]
+fn all_paths() {
+    use crate::{Grid, Vec2};
+
+    // two ways around the wall in the middle
+    let grid = Grid::<char>::parse("S..\n.#.\n..E").unwrap();
+    let end = Vec2::new(2, 2);
+    let open = |pos: &Vec2| {
+        grid.neighbors4(pos)
+            .filter(|&(_, &c)| c != '#')
+            .map(|(next, _)| next)
+            .collect::<Vec<_>>()
+    };
+
+    let all = bfs_all([Vec2::new(0, 0)], open, |&pos| pos == end);
+    assert_eq!(vec![end], all.goals);
+    assert_eq!(Some(4), all.goal_distance());
+    assert_eq!(2, all.count_paths(&end));
+    assert_eq!(Some(&2), all.path_counts().get(&end));
+    assert_eq!(8, all.on_paths(&all.goals).len());
+
+    let paths: Vec<Vec<Vec2>> = all.paths(&end).collect();
+    assert_eq!(2, paths.len());
+    assert_ne!(paths[0], paths[1]);
+    assert!(paths
+        .iter()
+        .all(|p| p.len() == 5 && p[0] == Vec2::new(0, 0)));
+
+    // going down first is more expensive, only one optimal path is left
+    let weighted = |&pos: &Vec2| {
+        open(&pos).into_iter().map(move |next| {
+            let cost = if next.y > pos.y && pos.x == 0 { 2 } else { 1 };
+            (next, cost)
+        })
+    };
+    let cheap = dijkstra_all([Vec2::new(0, 0)], weighted, |&pos| pos == end);
+    assert_eq!(Some(4), cheap.goal_distance());
+    assert_eq!(1, cheap.count_paths(&end));
+    assert_eq!(
+        HashSet::from([
+            Vec2::new(0, 0),
+            Vec2::new(1, 0),
+            Vec2::new(2, 0),
+            Vec2::new(2, 1),
+            end
+        ]),
+        cheap.on_paths(&[end])
+    );
+}
+
+#[test]
+fn all_paths_stop_early() {
+    // 2 is reached through 0 but still queued when the goal 1 is found
+    let edges = |&n: &u32| match n {
+        0 => vec![(1, 1), (2, 5)],
+        2 => vec![(3, 1)],
+        _ => vec![],
+    };
+    let all = dijkstra_all([0], edges, |&n| n == 1);
+    assert_eq!(Some(1), all.goal_distance());
+    assert_eq!(None, all.distance(&2));
+    assert_eq!(0, all.count_paths(&2));
+    assert_eq!(0, all.paths(&2).count());
+    assert!(all.on_paths(&[2]).is_empty());
+    assert_eq!(2, all.distances.len());
+}
+
+#[test]
+#[should_panic(expected = "positive step costs")]
+fn all_paths_free_step() {
+    dijkstra_all([0], |&n: &u32| [(n + 1, u32::from(n == 0))], |&n| n == 3);
+}
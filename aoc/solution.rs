@@ -0,0 +1,84 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    bench::{time, Timings},
+    ParseError,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "part1"),
+            Part::Two => write!(f, "part2"),
+        }
+    }
+}
+
+// one puzzle day, the input is parsed once and shared by both parts
+pub trait Solution {
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+    fn part1(input: &Self::Input<'_>) -> impl Display;
+    fn part2(input: &Self::Input<'_>) -> impl Display;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer {
+    pub part: Part,
+    pub value: String,
+    // the part alone, parsing isn't included
+    pub elapsed: Duration,
+}
+
+// parses once and solves the given parts in order
+pub fn solve<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<Answer>, ParseError> {
+    let parsed = S::parse(input)?;
+    let answers = parts
+        .iter()
+        .map(|&part| {
+            let now = Instant::now();
+            let value = match part {
+                Part::One => S::part1(&parsed).to_string(),
+                Part::Two => S::part2(&parsed).to_string(),
+            };
+            Answer {
+                part,
+                value,
+                elapsed: now.elapsed(),
+            }
+        })
+        .collect();
+    Ok(answers)
+}
+
+// type erased entry for the registry of the runner binary
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&str, &[Part]) -> Result<Vec<Answer>, ParseError>,
+    pub time: fn(&str) -> Result<Timings, ParseError>,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(number: u8) -> Self {
+        Day {
+            number,
+            solve: solve::<S>,
+            time: time::<S>,
+        }
+    }
+
+    pub fn name(&self) -> String {
+        format!("day{:02}", self.number)
+    }
+}
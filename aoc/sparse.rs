@@ -0,0 +1,246 @@
+use std::{
+    collections::HashMap,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Direction, Direction8, Grid, Vec2};
+
+// a grid without fixed size, only the cells that were set are stored
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Vec2, T>,
+    // inclusive corners of all stored cells
+    bounds: Bounds,
+}
+
+type Bounds = Option<(Vec2, Vec2)>;
+
+fn grow(bounds: Bounds, pos: &Vec2) -> Bounds {
+    match bounds {
+        Some((min, max)) => Some((min.min(*pos), max.max(*pos))),
+        None => Some((*pos, *pos)),
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // the cells of a dense grid that pass `keep`
+    pub fn from_dense(grid: &Grid<T>, keep: impl Fn(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        grid.iter_positions()
+            .filter(|(_, value)| keep(value))
+            .map(|(pos, value)| (pos, value.clone()))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // min and max corner of the stored cells, both inclusive
+    pub fn bounds(&self) -> Option<(Vec2, Vec2)> {
+        self.bounds
+    }
+
+    // width and height of the bounding box
+    pub fn size(&self) -> Vec2 {
+        match self.bounds {
+            Some((min, max)) => max - min + Vec2::broadcast(1),
+            None => Vec2::default(),
+        }
+    }
+
+    pub fn contains(&self, pos: &Vec2) -> bool {
+        self.cells.contains_key(pos)
+    }
+
+    pub fn value_for(&self, pos: &Vec2) -> Option<&T> {
+        self.cells.get(pos)
+    }
+
+    pub fn set_value_for(&mut self, pos: &Vec2, value: T) {
+        self.bounds = grow(self.bounds, pos);
+        self.cells.insert(*pos, value);
+    }
+
+    pub fn get_mut(&mut self, pos: &Vec2) -> Option<&mut T> {
+        self.cells.get_mut(pos)
+    }
+
+    pub fn get_or_insert_with(&mut self, pos: &Vec2, f: impl FnOnce() -> T) -> &mut T {
+        self.bounds = grow(self.bounds, pos);
+        self.cells.entry(*pos).or_insert_with(f)
+    }
+
+    pub fn remove(&mut self, pos: &Vec2) -> Option<T> {
+        let value = self.cells.remove(pos)?;
+
+        // only a cell on the edge can shrink the bounding box
+        if let Some((min, max)) = self.bounds {
+            if pos.x == min.x || pos.y == min.y || pos.x == max.x || pos.y == max.y {
+                self.bounds = self.cells.keys().fold(None, grow);
+            }
+        }
+
+        Some(value)
+    }
+
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> SparseGrid<U> {
+        SparseGrid {
+            cells: self
+                .cells
+                .iter()
+                .map(|(&pos, value)| (pos, f(value)))
+                .collect(),
+            bounds: self.bounds,
+        }
+    }
+
+    // in no particular order
+    pub fn iter_positions(&self) -> impl Iterator<Item = (Vec2, &T)> + '_ {
+        self.cells.iter().map(|(&pos, value)| (pos, value))
+    }
+
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Vec2> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter_positions()
+            .filter(move |(_, v)| *v == value)
+            .map(|(pos, _)| pos)
+    }
+
+    // the stored cells at pos + offset, empty ones are skipped
+    pub fn neighbors_with<'a, I>(
+        &'a self,
+        pos: &Vec2,
+        offsets: I,
+    ) -> impl Iterator<Item = (Vec2, &'a T)> + 'a
+    where
+        I: IntoIterator<Item = Vec2>,
+        I::IntoIter: 'a,
+    {
+        let pos = *pos;
+        offsets.into_iter().filter_map(move |offset| {
+            let next = pos + offset;
+            self.value_for(&next).map(|value| (next, value))
+        })
+    }
+
+    // up, right, down, left
+    pub fn neighbors4(&self, pos: &Vec2) -> impl Iterator<Item = (Vec2, &T)> + '_ {
+        self.neighbors_with(pos, Direction::ALL.map(Vec2::from))
+    }
+
+    // clockwise including the diagonals, starting up
+    pub fn neighbors8(&self, pos: &Vec2) -> impl Iterator<Item = (Vec2, &T)> + '_ {
+        self.neighbors_with(pos, Direction8::ALL.map(Vec2::from))
+    }
+
+    // the bounding box as dense grid, its top left corner ends up at (0 0)
+    pub fn to_dense(&self, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let min = self.bounds.map_or(Vec2::default(), |(min, _)| min);
+        self.to_dense_area(&min, self.size(), fill)
+    }
+
+    // the area min..min + size as dense grid, cells outside of it are dropped
+    pub fn to_dense_area(&self, min: &Vec2, size: Vec2, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(size.x, size.y, |p| {
+            self.value_for(&(*min + p)).unwrap_or(&fill).clone()
+        })
+    }
+}
+
+impl<T> FromIterator<(Vec2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Vec2, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Vec2, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Vec2, T)>>(&mut self, iter: I) {
+        for (pos, value) in iter {
+            self.set_value_for(&pos, value);
+        }
+    }
+}
+
+impl<T> Index<Vec2> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2) -> &Self::Output {
+        match self.cells.get(&pos) {
+            Some(value) => value,
+            None => panic!("no cell at position {}", pos),
+        }
+    }
+}
+
+impl<T> IndexMut<Vec2> for SparseGrid<T> {
+    fn index_mut(&mut self, pos: Vec2) -> &mut Self::Output {
+        match self.cells.get_mut(&pos) {
+            Some(value) => value,
+            None => panic!("no cell at position {}", pos),
+        }
+    }
+}
+
+#[test]
+fn sparse_grid() {
+    let mut grid = SparseGrid::new();
+    assert!(grid.is_empty());
+    assert_eq!(None, grid.bounds());
+
+    grid.set_value_for(&Vec2::new(-2, 1), 'a');
+    grid.set_value_for(&Vec2::new(3, -1), 'b');
+    *grid.get_or_insert_with(&Vec2::new(3, 0), || 'x') = 'c';
+    assert_eq!(3, grid.len());
+    assert_eq!(Some((Vec2::new(-2, -1), Vec2::new(3, 1))), grid.bounds());
+    assert_eq!(Vec2::new(6, 3), grid.size());
+    assert_eq!('c', grid[Vec2::new(3, 0)]);
+
+    let neighbors: String = grid.neighbors4(&Vec2::new(3, 0)).map(|(_, &c)| c).collect();
+    assert_eq!("b", neighbors);
+    assert_eq!(2, grid.neighbors8(&Vec2::new(2, 0)).count());
+
+    let dense = grid.to_dense('.');
+    assert_eq!(".....b.....ca.....", dense.data.iter().collect::<String>());
+
+    assert_eq!(Some('a'), grid.remove(&Vec2::new(-2, 1)));
+    assert_eq!(None, grid.remove(&Vec2::new(-2, 1)));
+    assert_eq!(Some((Vec2::new(3, -1), Vec2::new(3, 0))), grid.bounds());
+
+    let back = SparseGrid::from_dense(&dense, |&c| c != '.');
+    assert_eq!(3, back.len());
+    assert_eq!(Some(&'a'), back.value_for(&Vec2::new(0, 2)));
+    assert_eq!(
+        vec![Vec2::new(5, 1)],
+        back.positions_of(&'c').collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,150 @@
+use crate::{Grid, Vec2};
+
+impl<T: Clone> Grid<T> {
+    fn at(&self, x: i32, y: i32) -> T {
+        self.value_for_checked(&Vec2::new(x, y)).clone()
+    }
+
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |p| self.at(p.y, p.x))
+    }
+
+    // clockwise
+    pub fn rotate_right(&self) -> Self {
+        let h = self.height;
+        Grid::from_fn(self.height, self.width, |p| self.at(p.y, h - 1 - p.x))
+    }
+
+    // counter clockwise
+    pub fn rotate_left(&self) -> Self {
+        let w = self.width;
+        Grid::from_fn(self.height, self.width, |p| self.at(w - 1 - p.y, p.x))
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        let (w, h) = (self.width, self.height);
+        Grid::from_fn(w, h, |p| self.at(w - 1 - p.x, h - 1 - p.y))
+    }
+
+    // left becomes right
+    pub fn mirror_horizontal(&self) -> Self {
+        let w = self.width;
+        Grid::from_fn(w, self.height, |p| self.at(w - 1 - p.x, p.y))
+    }
+
+    // top becomes bottom
+    pub fn mirror_vertical(&self) -> Self {
+        let h = self.height;
+        Grid::from_fn(self.width, h, |p| self.at(p.x, h - 1 - p.y))
+    }
+
+    // the part of the grid covered by min..min + size, clamped to the grid
+    pub fn crop(&self, min: &Vec2, size: Vec2) -> Self {
+        let begin = min.max(Vec2::default());
+        let end = (*min + size).min(Vec2::new(self.width, self.height));
+        let size = (end - begin).max(Vec2::default());
+        Grid::from_fn(size.x, size.y, |p| {
+            self.value_for_checked(&(begin + p)).clone()
+        })
+    }
+
+    // surrounds the grid with `amount` cells of `fill` on every side
+    pub fn pad(&self, amount: i32, fill: T) -> Self {
+        let offset = Vec2::broadcast(amount);
+        Grid::from_fn(self.width + 2 * amount, self.height + 2 * amount, |p| {
+            self.value_for(&(p - offset)).unwrap_or(&fill).clone()
+        })
+    }
+
+    // repeats the whole grid `count` times in each direction
+    pub fn tile(&self, count: Vec2) -> Self {
+        let (w, h) = (self.width, self.height);
+        Grid::from_fn(w * count.x, h * count.y, |p| self.at(p.x % w, p.y % h))
+    }
+
+    // every cell becomes a block of factor.x * factor.y copies
+    pub fn scale(&self, factor: Vec2) -> Self {
+        let n = (factor.x * factor.y) as usize;
+        self.expand(factor, |cell| std::iter::repeat_n(cell.clone(), n))
+    }
+}
+
+impl<T> Grid<T> {
+    // replaces every cell with a block of factor.x * factor.y cells,
+    // `f` yields the block row by row, e.g. 'O' -> "[]" with a factor of (2 1)
+    pub fn expand<U, I>(&self, factor: Vec2, f: impl Fn(&T) -> I) -> Grid<U>
+    where
+        I: IntoIterator<Item = U>,
+    {
+        let width = self.width * factor.x;
+        let height = self.height * factor.y;
+        let mut data = Vec::with_capacity((width * height).max(0) as usize);
+
+        let block_width = factor.x as usize;
+        for row in self.data.chunks(self.width.max(1) as usize) {
+            let mut blocks: Vec<I::IntoIter> = row.iter().map(|c| f(c).into_iter()).collect();
+            for _ in 0..factor.y {
+                for block in blocks.iter_mut() {
+                    let before = data.len();
+                    data.extend(block.by_ref().take(block_width));
+                    assert!(
+                        data.len() - before == block_width,
+                        "expand needs {} cells per block",
+                        factor.x * factor.y
+                    );
+                }
+            }
+        }
+
+        Grid::new(width, height, data)
+    }
+}
+
+#[test]
+fn transforms() {
+    let grid = Grid::<char>::parse("abc\ndef").unwrap();
+    let text = |g: Grid<char>| -> String {
+        g.data
+            .chunks(g.width as usize)
+            .map(|row| row.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join("/")
+    };
+
+    assert_eq!("ad/be/cf", text(grid.transpose()));
+    assert_eq!("da/eb/fc", text(grid.rotate_right()));
+    assert_eq!("cf/be/ad", text(grid.rotate_left()));
+    assert_eq!("fed/cba", text(grid.rotate_180()));
+    assert_eq!("cba/fed", text(grid.mirror_horizontal()));
+    assert_eq!("def/abc", text(grid.mirror_vertical()));
+    assert_eq!("abc/def", text(grid.rotate_right().rotate_left()));
+
+    assert_eq!("bc/ef", text(grid.crop(&Vec2::new(1, 0), Vec2::new(5, 5))));
+    assert_eq!("e", text(grid.crop(&Vec2::new(1, 1), Vec2::new(1, 1))));
+    assert_eq!(
+        ".../.e./...",
+        text(grid.crop(&Vec2::new(1, 1), Vec2::new(1, 1)).pad(1, '.'))
+    );
+
+    assert_eq!(
+        "abcabc/defdef/abcabc/defdef",
+        text(grid.tile(Vec2::new(2, 2)))
+    );
+    assert_eq!("aabbcc/ddeeff", text(grid.scale(Vec2::new(2, 1))));
+    assert_eq!(
+        "ab/ab/cd/cd",
+        text(
+            Grid::<char>::parse("ab\ncd")
+                .unwrap()
+                .scale(Vec2::new(1, 2))
+        )
+    );
+
+    let warehouse = Grid::<char>::parse("#O.@").unwrap();
+    let wide = warehouse.expand(Vec2::new(2, 1), |&c| match c {
+        'O' => ['[', ']'],
+        '@' => ['@', '.'],
+        c => [c, c],
+    });
+    assert_eq!("##[]..@.", text(wide));
+}
@@ -0,0 +1,654 @@
+use std::{
+    fmt,
+    hash::Hash,
+    ops::{Add, AddAssign, Div, Index, IndexMut, Mul, Neg, Rem, Sub, SubAssign},
+};
+
+// the integer types vectors are built from
+pub trait Int:
+    Copy
+    + Default
+    + Ord
+    + Hash
+    + fmt::Debug
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + AddAssign
+    + SubAssign
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn overflowing_add(self, rhs: Self) -> (Self, bool);
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+    fn rem_euclid(self, rhs: Self) -> Self;
+}
+
+pub trait Signed: Int + Neg<Output = Self> {
+    fn abs(self) -> Self;
+    fn signum(self) -> Self;
+}
+
+macro_rules! int {
+    ($($t:ty),*) => {$(
+        impl Int for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+            fn checked_sub(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_sub(self, rhs)
+            }
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+            fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                <$t>::overflowing_add(self, rhs)
+            }
+            fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                <$t>::overflowing_sub(self, rhs)
+            }
+            fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                <$t>::overflowing_mul(self, rhs)
+            }
+            fn rem_euclid(self, rhs: Self) -> Self {
+                <$t>::rem_euclid(self, rhs)
+            }
+        }
+    )*};
+}
+
+macro_rules! signed {
+    ($($t:ty),*) => {$(
+        impl Signed for $t {
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+            fn signum(self) -> Self {
+                <$t>::signum(self)
+            }
+        }
+    )*};
+}
+
+int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+signed!(i8, i16, i32, i64, i128, isize);
+
+fn distance<T: Int>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+// component wise operations shared by the vectors with named components,
+// neighbors are delegated to the array based VecN
+macro_rules! vector {
+    ($name:ident, $n:literal { $($c:ident),+ }) => {
+        impl<T> $name<T> {
+            pub const fn new($($c: T),+) -> Self {
+                $name { $($c),+ }
+            }
+
+            // lossless width conversion, e.g. i32 -> i64
+            pub fn cast<U: From<T>>(self) -> $name<U> {
+                $name { $($c: U::from(self.$c)),+ }
+            }
+
+            // None if a component doesn't fit, e.g. a negative i32 -> usize
+            pub fn try_cast<U: TryFrom<T>>(self) -> Option<$name<U>> {
+                Some($name { $($c: U::try_from(self.$c).ok()?),+ })
+            }
+        }
+
+        impl<T: Int> $name<T> {
+            pub fn broadcast(v: T) -> Self {
+                $name { $($c: v),+ }
+            }
+
+            pub fn manhattan(self, other: $name<T>) -> T {
+                T::ZERO $(+ distance(self.$c, other.$c))+
+            }
+
+            pub fn chebyshev(self, other: $name<T>) -> T {
+                T::ZERO $(.max(distance(self.$c, other.$c)))+
+            }
+
+            // component wise
+            pub fn min(self, other: $name<T>) -> Self {
+                $name { $($c: self.$c.min(other.$c)),+ }
+            }
+
+            pub fn max(self, other: $name<T>) -> Self {
+                $name { $($c: self.$c.max(other.$c)),+ }
+            }
+
+            pub fn dot(self, other: $name<T>) -> T {
+                T::ZERO $(+ self.$c * other.$c)+
+            }
+
+            pub fn checked_add(self, rhs: $name<T>) -> Option<Self> {
+                Some($name { $($c: self.$c.checked_add(rhs.$c)?),+ })
+            }
+
+            pub fn checked_sub(self, rhs: $name<T>) -> Option<Self> {
+                Some($name { $($c: self.$c.checked_sub(rhs.$c)?),+ })
+            }
+
+            pub fn checked_mul(self, rhs: $name<T>) -> Option<Self> {
+                Some($name { $($c: self.$c.checked_mul(rhs.$c)?),+ })
+            }
+
+            // the flag is set if any of the components overflowed
+            pub fn overflowing_add(self, rhs: $name<T>) -> (Self, bool) {
+                let mut overflow = false;
+                let v = $name { $($c: {
+                    let (v, o) = self.$c.overflowing_add(rhs.$c);
+                    overflow |= o;
+                    v
+                }),+ };
+                (v, overflow)
+            }
+
+            pub fn overflowing_sub(self, rhs: $name<T>) -> (Self, bool) {
+                let mut overflow = false;
+                let v = $name { $($c: {
+                    let (v, o) = self.$c.overflowing_sub(rhs.$c);
+                    overflow |= o;
+                    v
+                }),+ };
+                (v, overflow)
+            }
+
+            pub fn overflowing_mul(self, rhs: $name<T>) -> (Self, bool) {
+                let mut overflow = false;
+                let v = $name { $($c: {
+                    let (v, o) = self.$c.overflowing_mul(rhs.$c);
+                    overflow |= o;
+                    v
+                }),+ };
+                (v, overflow)
+            }
+
+            // wraps every component into 0..size, e.g. for positions on a torus
+            pub fn rem_euclid(self, size: $name<T>) -> Self {
+                $name { $($c: self.$c.rem_euclid(size.$c)),+ }
+            }
+        }
+
+        impl<T: Signed> $name<T> {
+            pub fn signum(self) -> Self {
+                $name { $($c: self.$c.signum()),+ }
+            }
+
+            pub fn abs(self) -> Self {
+                $name { $($c: self.$c.abs()),+ }
+            }
+
+            // the neighbors sharing a face, 2 per axis
+            pub fn face_neighbors(self) -> impl Iterator<Item = Self> {
+                VecN::from(self).face_neighbors().map(Self::from)
+            }
+
+            // all neighbors including the diagonal ones, 3^n - 1
+            pub fn neighbors(self) -> impl Iterator<Item = Self> {
+                VecN::from(self).neighbors().map(Self::from)
+            }
+        }
+
+        impl<T> From<$name<T>> for VecN<$n, T> {
+            fn from(v: $name<T>) -> Self {
+                VecN([$(v.$c),+])
+            }
+        }
+
+        impl<T> From<VecN<$n, T>> for $name<T> {
+            fn from(v: VecN<$n, T>) -> Self {
+                let [$($c),+] = v.0;
+                $name { $($c),+ }
+            }
+        }
+
+        impl<T: Int> Add<$name<T>> for $name<T> {
+            type Output = $name<T>;
+            fn add(self, rhs: $name<T>) -> Self::Output {
+                $name { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl<T: Int> Sub<$name<T>> for $name<T> {
+            type Output = $name<T>;
+            fn sub(self, rhs: $name<T>) -> Self::Output {
+                $name { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl<T: Int> Mul<$name<T>> for $name<T> {
+            type Output = $name<T>;
+            fn mul(self, rhs: $name<T>) -> Self::Output {
+                $name { $($c: self.$c * rhs.$c),+ }
+            }
+        }
+
+        impl<T: Int> Div<$name<T>> for $name<T> {
+            type Output = $name<T>;
+            fn div(self, rhs: $name<T>) -> Self::Output {
+                $name { $($c: self.$c / rhs.$c),+ }
+            }
+        }
+
+        impl<T: Int> Mul<T> for $name<T> {
+            type Output = $name<T>;
+            fn mul(self, rhs: T) -> Self::Output {
+                $name { $($c: self.$c * rhs),+ }
+            }
+        }
+
+        impl<T: Signed> Neg for $name<T> {
+            type Output = $name<T>;
+            fn neg(self) -> Self::Output {
+                $name { $($c: -self.$c),+ }
+            }
+        }
+
+        impl<T: Int> AddAssign<$name<T>> for $name<T> {
+            fn add_assign(&mut self, rhs: $name<T>) {
+                $(self.$c += rhs.$c;)+
+            }
+        }
+
+        impl<T: Int> SubAssign<$name<T>> for $name<T> {
+            fn sub_assign(&mut self, rhs: $name<T>) {
+                $(self.$c -= rhs.$c;)+
+            }
+        }
+
+        impl<T: fmt::Display> fmt::Display for $name<T> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&VecN([$(&self.$c),+]), f)
+            }
+        }
+    };
+}
+
+#[derive(Default, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub struct Vec2<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+vector!(Vec2, 2 { x, y });
+
+impl<T: Int> Vec2<T> {
+    // z component of the 3d cross product, > 0 if `other` lies clockwise of `self`
+    pub fn cross(self, other: Vec2<T>) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+impl<T: Signed> Vec2<T> {
+    // y points down, so turning right maps up (0 -1) to right (1 0)
+    pub fn rotate_right(self) -> Self {
+        Vec2 {
+            x: -self.y,
+            y: self.x,
+        }
+    }
+
+    pub fn rotate_left(self) -> Self {
+        Vec2 {
+            x: self.y,
+            y: -self.x,
+        }
+    }
+}
+
+#[derive(Default, Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub struct Vec3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+vector!(Vec3, 3 { x, y, z });
+
+impl<T: Int> Vec3<T> {
+    pub fn cross(self, other: Vec3<T>) -> Self {
+        Vec3 {
+            x: self.y * other.z - self.z * other.y,
+            y: self.z * other.x - self.x * other.z,
+            z: self.x * other.y - self.y * other.x,
+        }
+    }
+}
+
+// points with any number of dimensions, e.g. VecN<4> for 4d cubes
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
+pub struct VecN<const N: usize, T = i32>(pub [T; N]);
+
+impl<const N: usize, T: Copy + Default> Default for VecN<N, T> {
+    fn default() -> Self {
+        VecN([T::default(); N])
+    }
+}
+
+impl<const N: usize, T> VecN<N, T> {
+    pub const fn new(components: [T; N]) -> Self {
+        VecN(components)
+    }
+
+    pub fn cast<U: From<T>>(self) -> VecN<N, U> {
+        VecN(self.0.map(U::from))
+    }
+
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<VecN<N, U>> {
+        let components: Option<Vec<U>> = self.0.into_iter().map(|c| U::try_from(c).ok()).collect();
+        components?.try_into().ok().map(VecN)
+    }
+}
+
+impl<const N: usize, T: Int> VecN<N, T> {
+    fn zip(self, other: VecN<N, T>, f: impl Fn(T, T) -> T) -> Self {
+        VecN(std::array::from_fn(|i| f(self.0[i], other.0[i])))
+    }
+
+    fn try_zip(self, other: VecN<N, T>, f: impl Fn(T, T) -> Option<T>) -> Option<Self> {
+        let mut result = self;
+        for i in 0..N {
+            result.0[i] = f(self.0[i], other.0[i])?;
+        }
+        Some(result)
+    }
+
+    fn overflowing_zip(self, other: VecN<N, T>, f: impl Fn(T, T) -> (T, bool)) -> (Self, bool) {
+        let mut result = self;
+        let mut overflow = false;
+        for i in 0..N {
+            let (v, o) = f(self.0[i], other.0[i]);
+            result.0[i] = v;
+            overflow |= o;
+        }
+        (result, overflow)
+    }
+
+    pub fn broadcast(v: T) -> Self {
+        VecN([v; N])
+    }
+
+    pub fn manhattan(self, other: VecN<N, T>) -> T {
+        (0..N).fold(T::ZERO, |sum, i| sum + distance(self.0[i], other.0[i]))
+    }
+
+    pub fn chebyshev(self, other: VecN<N, T>) -> T {
+        (0..N).fold(T::ZERO, |max, i| max.max(distance(self.0[i], other.0[i])))
+    }
+
+    pub fn min(self, other: VecN<N, T>) -> Self {
+        self.zip(other, T::min)
+    }
+
+    pub fn max(self, other: VecN<N, T>) -> Self {
+        self.zip(other, T::max)
+    }
+
+    pub fn dot(self, other: VecN<N, T>) -> T {
+        (0..N).fold(T::ZERO, |sum, i| sum + self.0[i] * other.0[i])
+    }
+
+    pub fn checked_add(self, rhs: VecN<N, T>) -> Option<Self> {
+        self.try_zip(rhs, T::checked_add)
+    }
+
+    pub fn checked_sub(self, rhs: VecN<N, T>) -> Option<Self> {
+        self.try_zip(rhs, T::checked_sub)
+    }
+
+    pub fn checked_mul(self, rhs: VecN<N, T>) -> Option<Self> {
+        self.try_zip(rhs, T::checked_mul)
+    }
+
+    pub fn overflowing_add(self, rhs: VecN<N, T>) -> (Self, bool) {
+        self.overflowing_zip(rhs, T::overflowing_add)
+    }
+
+    pub fn overflowing_sub(self, rhs: VecN<N, T>) -> (Self, bool) {
+        self.overflowing_zip(rhs, T::overflowing_sub)
+    }
+
+    pub fn overflowing_mul(self, rhs: VecN<N, T>) -> (Self, bool) {
+        self.overflowing_zip(rhs, T::overflowing_mul)
+    }
+
+    pub fn rem_euclid(self, size: VecN<N, T>) -> Self {
+        self.zip(size, T::rem_euclid)
+    }
+}
+
+impl<const N: usize, T: Signed> VecN<N, T> {
+    pub fn signum(self) -> Self {
+        VecN(self.0.map(T::signum))
+    }
+
+    pub fn abs(self) -> Self {
+        VecN(self.0.map(T::abs))
+    }
+
+    pub fn face_neighbors(self) -> impl Iterator<Item = Self> {
+        (0..2 * N).map(move |i| {
+            let mut next = self;
+            next.0[i / 2] += if i % 2 == 0 { -T::ONE } else { T::ONE };
+            next
+        })
+    }
+
+    pub fn neighbors(self) -> impl Iterator<Item = Self> {
+        // every offset in -1..=1 per axis is a base 3 number, the middle one is self
+        let count = 3usize.pow(N as u32);
+        (0..count).filter(move |&i| i != count / 2).map(move |i| {
+            let mut next = self;
+            let mut digits = i;
+            for c in next.0.iter_mut() {
+                match digits % 3 {
+                    0 => *c -= T::ONE,
+                    2 => *c += T::ONE,
+                    _ => {}
+                }
+                digits /= 3;
+            }
+            next
+        })
+    }
+}
+
+impl<const N: usize, T> Index<usize> for VecN<N, T> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize, T> IndexMut<usize> for VecN<N, T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize, T: Int> Add<VecN<N, T>> for VecN<N, T> {
+    type Output = VecN<N, T>;
+    fn add(self, rhs: VecN<N, T>) -> Self::Output {
+        self.zip(rhs, T::add)
+    }
+}
+
+impl<const N: usize, T: Int> Sub<VecN<N, T>> for VecN<N, T> {
+    type Output = VecN<N, T>;
+    fn sub(self, rhs: VecN<N, T>) -> Self::Output {
+        self.zip(rhs, T::sub)
+    }
+}
+
+impl<const N: usize, T: Int> Mul<VecN<N, T>> for VecN<N, T> {
+    type Output = VecN<N, T>;
+    fn mul(self, rhs: VecN<N, T>) -> Self::Output {
+        self.zip(rhs, T::mul)
+    }
+}
+
+impl<const N: usize, T: Int> Div<VecN<N, T>> for VecN<N, T> {
+    type Output = VecN<N, T>;
+    fn div(self, rhs: VecN<N, T>) -> Self::Output {
+        self.zip(rhs, T::div)
+    }
+}
+
+impl<const N: usize, T: Int> Mul<T> for VecN<N, T> {
+    type Output = VecN<N, T>;
+    fn mul(self, rhs: T) -> Self::Output {
+        VecN(self.0.map(|c| c * rhs))
+    }
+}
+
+impl<const N: usize, T: Signed> Neg for VecN<N, T> {
+    type Output = VecN<N, T>;
+    fn neg(self) -> Self::Output {
+        VecN(self.0.map(T::neg))
+    }
+}
+
+impl<const N: usize, T: Int> AddAssign<VecN<N, T>> for VecN<N, T> {
+    fn add_assign(&mut self, rhs: VecN<N, T>) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize, T: Int> SubAssign<VecN<N, T>> for VecN<N, T> {
+    fn sub_assign(&mut self, rhs: VecN<N, T>) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize, T: fmt::Display> fmt::Display for VecN<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        for (i, c) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}", c)?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+use std::collections::HashSet;
+
+#[test]
+fn vec2() {
+    let up = Vec2::new(0, -1);
+    let right = Vec2::new(1, 0);
+    assert_eq!(right, up.rotate_right());
+    assert_eq!(up, right.rotate_left());
+    assert_eq!(-up, up.rotate_right().rotate_right());
+
+    let a = Vec2::new(3, -4);
+    let b = Vec2::new(-1, 2);
+    assert_eq!(10, a.manhattan(b));
+    assert_eq!(6, a.chebyshev(b));
+    assert_eq!(Vec2::new(1, -1), a.signum());
+    assert_eq!(Vec2::new(3, 4), a.abs());
+    assert_eq!(Vec2::new(-1, -4), a.min(b));
+    assert_eq!(Vec2::new(3, 2), a.max(b));
+    assert_eq!(-11, a.dot(b));
+    assert_eq!(2, a.cross(b));
+    assert_eq!(Vec2::new(6, -8), a * 2);
+
+    let mut c = a;
+    c += b;
+    assert_eq!(Vec2::new(2, -2), c);
+    c -= b;
+    assert_eq!(a, c);
+}
+
+#[test]
+fn vec2_widths() {
+    let a: Vec2<i64> = Vec2::new(3, -4).cast();
+    assert_eq!(
+        Vec2::new(3_000_000_000_003, -4),
+        a + Vec2::new(3_000_000_000_000, 0)
+    );
+    assert_eq!(None, Vec2::new(3, -4).try_cast::<usize>());
+    assert_eq!(Some(Vec2::new(3usize, 4)), Vec2::new(3, 4).try_cast());
+
+    let max = Vec2::new(i32::MAX, 0);
+    assert_eq!(None, max.checked_add(Vec2::new(1, 0)));
+    assert_eq!(
+        Some(Vec2::new(i32::MAX, 1)),
+        max.checked_add(Vec2::new(0, 1))
+    );
+    assert_eq!(
+        (Vec2::new(i32::MIN, 0), true),
+        max.overflowing_add(Vec2::new(1, 0))
+    );
+    assert_eq!(None, Vec2::new(0u8, 1).checked_sub(Vec2::new(0, 2)));
+    assert_eq!(7u8, Vec2::new(2u8, 9).manhattan(Vec2::new(4, 4)));
+
+    let floor = Vec2::new(11, 7);
+    assert_eq!(Vec2::new(10, 3), Vec2::new(-1, 17).rem_euclid(floor));
+}
+
+#[test]
+fn vec3() {
+    let a = Vec3::new(1, 2, 3);
+    let b = Vec3::new(4, -5, 6);
+    assert_eq!(Vec3::new(5, -3, 9), a + b);
+    assert_eq!(12, a.dot(b));
+    assert_eq!(Vec3::new(27, 6, -13), a.cross(b));
+    assert_eq!(13, a.manhattan(b));
+    assert_eq!(7, a.chebyshev(b));
+    assert_eq!("(1 2 3)", a.to_string());
+
+    let faces: HashSet<Vec3> = a.face_neighbors().collect();
+    assert_eq!(6, faces.len());
+    assert!(faces.iter().all(|&n| n.manhattan(a) == 1));
+
+    let all: HashSet<Vec3> = a.neighbors().collect();
+    assert_eq!(26, all.len());
+    assert!(all.iter().all(|&n| n.chebyshev(a) == 1));
+}
+
+#[test]
+fn vec_n() {
+    let a = VecN::new([1, 2, 3, 4]);
+    let b = VecN::broadcast(2);
+    assert_eq!(VecN([3, 4, 5, 6]), a + b);
+    assert_eq!(VecN([-1, -2, -3, -4]), -a);
+    assert_eq!(20, a.dot(b));
+    assert_eq!(4, a[3]);
+    assert_eq!(8, a.face_neighbors().count());
+    assert_eq!(80, a.neighbors().collect::<HashSet<_>>().len());
+    assert_eq!(None, a.checked_sub(b).unwrap().try_cast::<u8>());
+
+    let v: VecN<2> = Vec2::new(3, 4).into();
+    assert_eq!(Vec2::new(3, 4), Vec2::from(v));
+    assert_eq!(
+        vec![
+            Vec2::new(2, 4),
+            Vec2::new(4, 4),
+            Vec2::new(3, 3),
+            Vec2::new(3, 5)
+        ],
+        Vec2::new(3, 4).face_neighbors().collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,42 @@
+use aoc::{parse_at, Counter, ParseError, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = (Vec<usize>, Vec<usize>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut left = vec![];
+        let mut right = vec![];
+
+        for line in input.lines() {
+            let mut words = line.split_whitespace();
+            match (words.next(), words.next(), words.next()) {
+                (Some(l), Some(r), None) => {
+                    left.push(parse_at(input, l)?);
+                    right.push(parse_at(input, r)?);
+                }
+                _ => return Err(ParseError::at(input, line, "expected two numbers")),
+            }
+        }
+
+        left.sort();
+        right.sort();
+
+        Ok((left, right))
+    }
+
+    fn part1((left, right): &Self::Input<'_>) -> impl std::fmt::Display {
+        left.iter()
+            .zip(right.iter())
+            .map(|(l, r)| l.abs_diff(*r))
+            .sum::<usize>()
+    }
+
+    fn part2((left, right): &Self::Input<'_>) -> impl std::fmt::Display {
+        let counts: Counter<usize> = right.iter().copied().collect();
+        left.iter()
+            .map(|number| number * counts.count(number))
+            .sum::<usize>()
+    }
+}
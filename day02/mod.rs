@@ -0,0 +1,97 @@
+use aoc::{parse_at, ParseError, Solution};
+use itertools::Itertools;
+
+#[derive(PartialEq, Eq)]
+enum Direction {
+    Increasing,
+    Decreasing,
+}
+
+#[derive(PartialEq, Eq)]
+enum Safety {
+    Safe,
+    Unsafe,
+}
+
+fn validate_report_part1(report: &[i32]) -> Safety {
+    let mut direction: Option<Direction> = None;
+
+    for (a, b) in report.iter().tuple_windows() {
+        let diff = a - b;
+
+        // distance too big
+        if diff.abs() > 3 {
+            return Safety::Unsafe;
+        }
+
+        // calc direction of current comparison
+        let dir = match diff.signum() {
+            -1 => Direction::Increasing,
+            1 => Direction::Decreasing,
+            _ => {
+                return Safety::Unsafe;
+            } // equals 0
+        };
+
+        match &direction {
+            None => {
+                direction = Some(dir);
+            }
+            Some(i) => {
+                if i != &dir {
+                    return Safety::Unsafe;
+                }
+            }
+        }
+    }
+
+    Safety::Safe
+}
+
+fn validate_report_part2(report: &[i32]) -> Safety {
+    if validate_report_part1(report) == Safety::Unsafe {
+        for index in 0..report.len() {
+            let mut copy = report.to_vec();
+            copy.remove(index);
+
+            if validate_report_part1(&copy) == Safety::Safe {
+                return Safety::Safe;
+            }
+        }
+
+        return Safety::Unsafe;
+    }
+
+    Safety::Safe
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|s| parse_at(input, s))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(reports: &Self::Input<'_>) -> impl std::fmt::Display {
+        reports
+            .iter()
+            .filter(|r| validate_report_part1(r) == Safety::Safe)
+            .count()
+    }
+
+    fn part2(reports: &Self::Input<'_>) -> impl std::fmt::Display {
+        reports
+            .iter()
+            .filter(|r| validate_report_part2(r) == Safety::Safe)
+            .count()
+    }
+}
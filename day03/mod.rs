@@ -0,0 +1,75 @@
+use aoc::{parse_at, ParseError, Solution};
+use regex::Regex;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+// the valid instructions in the corrupted memory, everything else is noise
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    let re = Regex::new(r"do\(\)|don't\(\)|mul\((\d+),(\d+)\)").unwrap();
+
+    re.captures_iter(input)
+        .map(|caps| match &caps[0] {
+            "do()" => Ok(Instruction::Do),
+            "don't()" => Ok(Instruction::Dont),
+            _ => {
+                let number = |i| parse_at(input, caps.get(i).unwrap().as_str());
+                Ok(Instruction::Mul(number(1)?, number(2)?))
+            }
+        })
+        .collect()
+}
+
+fn do_part1(instructions: &[Instruction]) -> i32 {
+    let mut result = 0;
+    for instruction in instructions {
+        if let Instruction::Mul(num0, num1) = instruction {
+            result += num0 * num1;
+        }
+    }
+
+    result
+}
+
+fn do_part2(instructions: &[Instruction]) -> i32 {
+    let mut action = true;
+    let mut result = 0;
+    for instruction in instructions {
+        match instruction {
+            Instruction::Do => {
+                action = true;
+            }
+            Instruction::Dont => {
+                action = false;
+            }
+            Instruction::Mul(num0, num1) => {
+                if action {
+                    result += num0 * num1;
+                }
+            }
+        }
+    }
+    result
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_instructions(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(input)
+    }
+}
@@ -0,0 +1,33 @@
+use aoc::{Grid, Orientations, ParseError, Solution};
+
+fn count(grid: &Grid<char>, pattern: &str) -> usize {
+    let pattern = Grid::<char>::parse_pattern(pattern, '.').expect("valid pattern");
+    grid.find_pattern(&pattern, Orientations::Rotations).len()
+}
+
+fn do_part1(grid: &Grid<char>) -> usize {
+    // the rotations cover the straight and the diagonal directions in both ways
+    count(grid, "XMAS") + count(grid, "X...\n.M..\n..A.\n...S")
+}
+
+fn do_part2(grid: &Grid<char>) -> usize {
+    count(grid, "M.S\n.A.\nM.S")
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(input)
+    }
+}
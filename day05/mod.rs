@@ -0,0 +1,116 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use aoc::{parse_at, ParseError, Solution};
+
+// the pages that have to come before a page, and the page lists of every update
+pub struct PrintQueue {
+    order_rules: HashMap<usize, HashSet<usize>>,
+    updates: Vec<Vec<usize>>,
+}
+
+fn parse_queue(input: &str) -> Result<PrintQueue, ParseError> {
+    let mut order_rules: HashMap<usize, HashSet<usize>> = HashMap::new();
+    let mut updates = Vec::new();
+
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        if let Some((page0, page1)) = line.split_once('|') {
+            let page0 = parse_at(input, page0)?;
+            let page1 = parse_at(input, page1)?;
+            order_rules.entry(page1).or_default().insert(page0);
+        } else {
+            let pages = line
+                .split(',')
+                .map(|page| parse_at(input, page))
+                .collect::<Result<Vec<usize>, _>>()?;
+            updates.push(pages);
+        }
+    }
+
+    Ok(PrintQueue {
+        order_rules,
+        updates,
+    })
+}
+
+fn do_part1(queue: &PrintQueue) -> usize {
+    let order_rules = &queue.order_rules;
+
+    let mut result = 0;
+    for pages in &queue.updates {
+        let mut valid_manual = true;
+
+        for (n, p) in pages.iter().enumerate() {
+            if let Some(rules) = order_rules.get(p) {
+                for a in pages.iter().skip(n + 1) {
+                    if rules.contains(a) {
+                        valid_manual = false;
+                        break;
+                    }
+                }
+            }
+        }
+
+        if valid_manual {
+            let middle = pages.len() / 2;
+            result += pages[middle];
+        }
+    }
+
+    result
+}
+
+fn do_part2(queue: &PrintQueue) -> usize {
+    let order_rules = &queue.order_rules;
+
+    let mut result = 0;
+    for pages in &queue.updates {
+        let mut broken = false;
+
+        for (n, p) in pages.iter().enumerate() {
+            if let Some(rules) = order_rules.get(p) {
+                for a in pages.iter().skip(n + 1) {
+                    if rules.contains(a) {
+                        broken = true;
+                        break;
+                    }
+                }
+            }
+        }
+
+        if broken {
+            let mut pages = pages.clone();
+            pages.sort_by(|a, b| {
+                if order_rules.get(a).map(|s| s.contains(b)).unwrap_or(false) {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            });
+
+            let middle = pages.len() / 2;
+            result += pages[middle];
+        }
+    }
+
+    result
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input<'a> = PrintQueue;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_queue(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(input)
+    }
+}
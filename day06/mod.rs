@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+
+use aoc::{brent, Grid, ParseError, Solution, Vec2};
+
+#[derive(Clone)]
+pub struct Map {
+    width: i32,
+    height: i32,
+    start: Vec2,
+    field: Vec<char>,
+}
+
+#[derive(PartialEq, Eq)]
+pub enum Field {
+    Empty,
+    Player,
+    Wall,
+    OutOfBounds,
+}
+
+impl Map {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (grid, markers) = Grid::<char>::parse_with_markers(input, "^")?;
+        let start = match markers.get(&'^').map(|starts| starts.as_slice()) {
+            Some([start]) => *start,
+            _ => return Err(ParseError::new("expected exactly one guard '^'")),
+        };
+
+        Ok(Map {
+            width: grid.width,
+            height: grid.height,
+            start,
+            field: grid.data,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    #[allow(dead_code)]
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn start(&self) -> Vec2 {
+        self.start
+    }
+
+    pub fn test(&self, pos: &Vec2) -> Field {
+        if pos.x >= 0 && pos.x < self.width && pos.y >= 0 && pos.y < self.height {
+            let index = usize::try_from(pos.y * self.width + pos.x).unwrap();
+            return match self.field[index] {
+                '#' => Field::Wall,
+                '^' => Field::Player,
+                _ => Field::Empty,
+            };
+        }
+
+        Field::OutOfBounds
+    }
+
+    pub fn set(&mut self, pos: &Vec2, c: char) {
+        if pos.x >= 0 && pos.x < self.width && pos.y >= 0 && pos.y < self.height {
+            let index = usize::try_from(pos.y * self.width + pos.x).unwrap();
+            self.field[index] = c;
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn print(&self) {
+        Grid::new(self.width, self.height, self.field.clone())
+            .render()
+            .print();
+    }
+
+    #[allow(dead_code)]
+    pub fn set_wall(&mut self, pos: &Vec2, v: bool) {
+        if pos.x >= 0 && pos.x < self.width && pos.y >= 0 && pos.y < self.height {
+            let index = usize::try_from(pos.y * self.width + pos.x).unwrap();
+            self.field[index] = if v { '#' } else { '.' };
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn check(&self, pos: &Vec2) -> Option<char> {
+        if pos.x >= 0 && pos.x < self.width && pos.y >= 0 && pos.y < self.height {
+            let index = usize::try_from(pos.y * self.width + pos.x).unwrap();
+            return Some(self.field[index]);
+        }
+        None
+    }
+
+    #[allow(dead_code)]
+    pub fn mark_visited(&mut self, pos: &Vec2) {
+        if pos.x >= 0 && pos.x < self.width && pos.y >= 0 && pos.y < self.height {
+            let index = usize::try_from(pos.y * self.width + pos.x).unwrap();
+            self.field[index] = 'A';
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn count_visited(&self) -> usize {
+        self.field.iter().filter(|c| c == &&'A').count()
+    }
+}
+
+fn do_part1(m: &Map) -> usize {
+    let mut guard = Some((m.start(), Vec2 { x: 0, y: -1 }));
+
+    let mut steps: HashSet<Vec2> = HashSet::new();
+
+    while let Some(current) = guard {
+        steps.insert(current.0);
+        guard = guard_step(m, &current);
+    }
+
+    steps.len()
+}
+
+// the guard walks on or turns right in front of a wall, None once it leaves the map,
+// turning on the spot keeps it from walking into a second wall right behind the first
+fn guard_step(map: &Map, &(pos, dir): &(Vec2, Vec2)) -> Option<(Vec2, Vec2)> {
+    let next_pos = pos + dir;
+    match map.test(&next_pos) {
+        Field::Wall => Some((pos, dir.rotate_right())),
+        Field::OutOfBounds => None,
+        _ => Some((next_pos, dir)),
+    }
+}
+
+fn do_part2_is_time_loop(map: &Map) -> bool {
+    let guard = (map.start(), Vec2 { x: 0, y: -1 });
+    brent(guard, |guard| guard_step(map, guard)).is_some()
+}
+
+fn do_part2(map: &Map) -> usize {
+    let mut map = map.clone();
+    let mut timeloops: usize = 0;
+
+    for y in 0..map.height {
+        for x in 0..map.width {
+            let pos = Vec2 { x, y };
+            if map.test(&pos) == Field::Empty {
+                map.set(&pos, '#'); // set extra wall
+
+                if do_part2_is_time_loop(&map) {
+                    timeloops += 1;
+                }
+
+                map.set(&pos, '.'); // remove extra wall
+            }
+        }
+    }
+
+    timeloops
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = Map;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Map::new(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(input)
+    }
+}
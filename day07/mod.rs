@@ -0,0 +1,131 @@
+use aoc::{parse_at, ParseError, Solution};
+
+#[derive(Clone, Debug, Copy)]
+enum Op {
+    Add,
+    Mul,
+    And,
+}
+
+#[allow(dead_code)]
+fn print_combination(result: usize, arguments: &[usize], operations: &[Op]) {
+    let ops = operations.iter().map(|op| match op {
+        Op::Add => "+",
+        Op::Mul => "*",
+        Op::And => "||",
+    });
+
+    print!("{} = {}", result, arguments[0]);
+    for (op, arg) in ops.zip(arguments.iter().skip(1)) {
+        print!(" {} {}", op, arg);
+    }
+    println!(); // flush
+}
+
+fn is_valid_combination(result: usize, arguments: &[usize], operations: &[Op]) -> bool {
+    assert_eq!(operations.len() + 1, arguments.len());
+
+    let mut val = arguments[0];
+
+    fn and(a: usize, b: usize) -> usize {
+        format!("{a}{b}").parse::<usize>().unwrap()
+    }
+
+    for (op, &arg) in operations.iter().zip(arguments.iter().skip(1)) {
+        val = match op {
+            Op::Add => val + arg,
+            Op::Mul => val * arg,
+            Op::And => and(val, arg),
+        };
+
+        if val > result {
+            return false;
+        }
+    }
+
+    result == val
+}
+
+// the test value and the numbers to combine
+type Equation = (usize, Vec<usize>);
+
+fn parse_equations(input: &str) -> Result<Vec<Equation>, ParseError> {
+    input
+        .trim_end()
+        .lines()
+        .map(|line| {
+            let (r, args) = line
+                .split_once(':')
+                .ok_or_else(|| ParseError::at(input, line, "expected 'value: numbers'"))?;
+
+            let args = args
+                .split_whitespace()
+                .map(|arg| parse_at(input, arg))
+                .collect::<Result<Vec<usize>, _>>()?;
+            if args.is_empty() {
+                return Err(ParseError::at(input, line, "expected at least one number"));
+            }
+
+            Ok((parse_at(input, r.trim())?, args))
+        })
+        .collect()
+}
+
+fn do_any_part(equations: &[Equation], valid_operations: &[Op]) -> usize {
+    let mut calibration_result: usize = 0;
+
+    let base: usize = valid_operations.len();
+
+    for (r, args) in equations {
+        let r = *r;
+        let op_count = args.len() - 1;
+        let max_combinations = base.pow(u32::try_from(op_count).unwrap());
+
+        let mut current_ops = vec![Op::Add; op_count];
+
+        for i in 0..max_combinations {
+            let mut bits: usize = i;
+            let mut op_index = 0;
+
+            while bits > 0 {
+                current_ops[op_index] = valid_operations[bits % base];
+                op_index += 1;
+                bits /= 3;
+            }
+
+            if is_valid_combination(r, args, &current_ops) {
+                // print_combination(r, &args, &current_ops);
+                calibration_result += r;
+                break;
+            }
+        }
+    }
+
+    calibration_result
+}
+
+fn do_part1(equations: &[Equation]) -> usize {
+    do_any_part(equations, &[Op::Add, Op::Mul])
+}
+
+fn do_part2(equations: &[Equation]) -> usize {
+    do_any_part(equations, &[Op::Add, Op::Mul, Op::And])
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Vec<Equation>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_equations(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(input)
+    }
+}
@@ -0,0 +1,143 @@
+use aoc::{CellGlyph, Grid, ParseError, Solution, SparseGrid, Vec2};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Empty,
+    Antenna(char),
+    Resonance,
+}
+
+impl CellGlyph for Field {
+    fn glyph(&self) -> char {
+        match self {
+            Field::Empty => '.',
+            Field::Antenna(c) => *c,
+            Field::Resonance => '#',
+        }
+    }
+}
+
+struct Map {
+    size: Vec2,
+    fields: SparseGrid<Field>,
+}
+
+impl Map {
+    pub fn new(grid: &Grid<char>) -> Self {
+        let fields = grid
+            .iter_positions()
+            .filter(|&(_, &c)| c != '.')
+            .map(|(pos, &c)| (pos, Field::Antenna(c)))
+            .collect();
+
+        Self {
+            size: Vec2::new(grid.width, grid.height),
+            fields,
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn print(&self) {
+        self.fields
+            .to_dense_area(&Vec2::default(), self.size, Field::Empty)
+            .render()
+            .print();
+    }
+
+    pub fn add_resonance(&mut self, pos: &Vec2) {
+        if self.contains(pos) {
+            self.fields.set_value_for(pos, Field::Resonance);
+        }
+    }
+
+    pub fn contains(&self, pos: &Vec2) -> bool {
+        pos.x >= 0 && pos.x < self.size.x && pos.y >= 0 && pos.y < self.size.y
+    }
+
+    pub fn resonances(&self) -> usize {
+        self.fields.positions_of(&Field::Resonance).count()
+    }
+
+    // every ordered pair of different antennas sharing a frequency
+    pub fn antenna_pairs(&self) -> Vec<(Vec2, Vec2)> {
+        let mut pairs = Vec::new();
+        for (a, field_a) in self.fields.iter_positions() {
+            for (b, field_b) in self.fields.iter_positions() {
+                if a != b && matches!(field_a, Field::Antenna(_)) && field_a == field_b {
+                    pairs.push((a, b));
+                }
+            }
+        }
+        pairs
+    }
+}
+
+fn do_part1(grid: &Grid<char>) -> usize {
+    let mut map = Map::new(grid);
+
+    // let antenna: Vec<Vec2> = input
+    //     .lines()// loop y
+    //     .enumerate()
+    //     .flat_map(|(y, l)| {
+    //         l.chars() // loop x
+    //             .enumerate()
+    //             .filter(|(_, c)| *c != '.')
+    //             .map(|(x, _)| Vec2 {
+    //                 x: x as i32,
+    //                 y: y as i32,
+    //             })
+    //             .collect::<Vec<_>>()
+    //     })
+    //     .collect();
+
+    // let antenna: Vec<Vec2> = input
+    //     .chars()
+    //     .filter(|&c| !"\r\n".contains(c)) // remove new lines
+    //     .enumerate()
+    //     .filter(|(_, c)| *c != '.') // remove wrong data
+    //     .map(|(i, _)| Vec2 {
+    //         // transform to new type
+    //         x: (i % w) as i32,
+    //         y: (i % w) as i32,
+    //     })
+    //     .collect();
+
+    // println!("{:?}", antenna);
+
+    // both orders of every pair show up, so only the resonance behind a is needed
+    for (a, b) in map.antenna_pairs() {
+        map.add_resonance(&(a + a - b));
+    }
+
+    map.resonances()
+}
+
+fn do_part2(grid: &Grid<char>) -> usize {
+    let mut map = Map::new(grid);
+
+    for (a, b) in map.antenna_pairs() {
+        for (pos, _) in grid.ray(&a, a - b) {
+            map.add_resonance(&pos);
+        }
+    }
+
+    map.resonances()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(input)
+    }
+}
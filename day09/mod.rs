@@ -1,7 +1,21 @@
-#[allow(dead_code)]
-const INPUT_DATA: &str = include_str!("input.txt");
-#[allow(dead_code)]
-const TEST_DATA: &str = include_str!("test.txt");
+use aoc::{ParseError, Solution};
+
+// the disk map is a single line of digits, trailing whitespace is ignored
+fn parse_disk_map(input: &str) -> Result<Vec<usize>, ParseError> {
+    let line = input.trim_end();
+    if line.is_empty() {
+        return Err(ParseError::new("expected a disk map"));
+    }
+
+    line.char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10).map(|d| d as usize).ok_or_else(|| {
+                let part = &line[i..i + c.len_utf8()];
+                ParseError::at(input, part, "expected a digit")
+            })
+        })
+        .collect()
+}
 
 #[derive(Clone, Copy, PartialEq, Eq)]
 enum Entry {
@@ -9,14 +23,13 @@ enum Entry {
     Emtpy,
 }
 
-pub fn do_part1(input: &str) -> usize {
+pub fn do_part1(disk_map: &[usize]) -> usize {
     let mut file_index = 0;
     let mut is_file = true;
 
-    let mut data: Vec<Entry> = input
-        .chars()
-        .flat_map(|c| {
-            let repeats = c.to_digit(10).unwrap() as usize;
+    let mut data: Vec<Entry> = disk_map
+        .iter()
+        .flat_map(|&repeats| {
             let mut entry = Entry::Emtpy;
 
             if is_file {
@@ -31,7 +44,7 @@ pub fn do_part1(input: &str) -> usize {
         .collect();
 
     let mut l = 0;
-    let mut r = data.len() - 1;
+    let mut r = data.len().saturating_sub(1);
     while l < r {
         if data[l] == Entry::Emtpy {
             data[l] = data[r];
@@ -57,14 +70,13 @@ pub fn do_part1(input: &str) -> usize {
     result
 }
 
-pub fn do_part2(input: &str) -> usize {
+pub fn do_part2(disk_map: &[usize]) -> usize {
     let mut file_index = 0;
     let mut is_file = true;
 
-    let mut data: Vec<Entry> = input
-        .chars()
-        .flat_map(|c| {
-            let repeats = c.to_digit(10).unwrap() as usize;
+    let mut data: Vec<Entry> = disk_map
+        .iter()
+        .flat_map(|&repeats| {
             let mut entry = Entry::Emtpy;
 
             if is_file {
@@ -147,17 +159,20 @@ pub fn do_part2(input: &str) -> usize {
     result
 }
 
-fn main() {
-    println!("part1 {}", do_part1(INPUT_DATA));
-    println!("part2 {}", do_part2(INPUT_DATA));
-}
+pub struct Day09;
 
-#[test]
-fn part1() {
-    assert_eq!(1928, do_part1(TEST_DATA));
-}
+impl Solution for Day09 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_disk_map(input)
+    }
 
-#[test]
-fn part2() {
-    assert_eq!(2858, do_part2(TEST_DATA));
+    fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(input)
+    }
 }
@@ -0,0 +1,97 @@
+use aoc::{bfs, bfs_all, Color, Grid, ParseError, Solution, Vec2};
+
+struct Height(usize);
+
+impl TryFrom<char> for Height {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c.to_digit(10) {
+            Some(d) => Ok(Height(d as usize)),
+            None => Err(ParseError::new(format!(
+                "expected a height 0-9, found '{}'",
+                c
+            ))),
+        }
+    }
+}
+
+pub struct TopoMap {
+    grid: Grid<usize>,
+    start: Vec<Vec2>,
+}
+
+impl TopoMap {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (grid, markers) = Grid::<Height>::parse_with_markers(input, "0")?;
+        let start = markers.get(&'0').cloned().unwrap_or_default();
+        Ok(TopoMap {
+            grid: grid.map(|h| h.0),
+            start,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn draw(&self) {
+        // the trail heads stand out
+        self.grid
+            .render()
+            .color(self.start.iter().copied(), Color::Green)
+            .print();
+    }
+}
+
+// every step goes up by exactly one
+fn uphill(grid: &Grid<usize>, pos: Vec2) -> impl Iterator<Item = Vec2> + '_ {
+    let height = grid[pos];
+    grid.neighbors4(&pos)
+        .filter(move |&(_, &next)| next == height + 1)
+        .map(|(next, _)| next)
+}
+
+pub fn do_part1(map: &TopoMap) -> usize {
+    let mut result = 0;
+    for &pos in map.start.iter() {
+        let reachable = bfs([pos], |&p| uphill(&map.grid, p), |_| false);
+        result += reachable
+            .distances
+            .keys()
+            .filter(|&&p| map.grid[p] == 9)
+            .count();
+    }
+
+    result
+}
+
+// the rating counts the distinct trails from all trailheads
+pub fn do_part2(map: &TopoMap) -> usize {
+    let trails = bfs_all(
+        map.start.iter().copied(),
+        |&p| uphill(&map.grid, p),
+        |_| false,
+    );
+    let counts = trails.path_counts();
+
+    map.grid
+        .positions_of(&9)
+        .filter_map(|p| counts.get(&p))
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = TopoMap;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        TopoMap::new(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(input)
+    }
+}
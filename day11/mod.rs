@@ -0,0 +1,106 @@
+use aoc::{memoize, parse_at, Counter, ParseError, Solution};
+
+// 1.   0 -> 1
+// 2.   even -> split in two
+//      1000 -> 10 and 00 -> 10 + 0
+// 3.   multiply by 2024
+// 4.   order is preserved
+
+pub fn do_part1(stones: &[usize]) -> usize {
+    // order doesn't matter for the count, equal stones are blinked together
+    let mut stones: Counter<usize> = stones.iter().copied().collect();
+
+    let blinks = 25;
+
+    for _ in 0..blinks {
+        let mut result = Counter::new();
+
+        for (&stone, count) in stones.iter() {
+            // rule 1
+            if stone == 0 {
+                result.add_n(1, count);
+                continue;
+            }
+
+            // rule 2, even number of digits
+            let mut l = stone.to_string();
+            if l.len().is_multiple_of(2) {
+                let r = l.split_off(l.len() / 2);
+                result.add_n(l.parse().unwrap(), count);
+                result.add_n(r.parse().unwrap(), count);
+                continue;
+            }
+
+            result.add_n(stone * 2024, count);
+        }
+
+        stones = result;
+    }
+
+    stones.total()
+}
+
+// the number of stones a single stone turns into
+fn count_stones(
+    count: &mut dyn FnMut((usize, usize)) -> usize,
+    (stone, blinks): (usize, usize),
+) -> usize {
+    if blinks == 0 {
+        return 1;
+    }
+
+    // rule 1
+    if stone == 0 {
+        return count((1, blinks - 1));
+    }
+
+    // rule 2, even number of digits
+    let mut l = stone.to_string();
+    if l.len().is_multiple_of(2) {
+        let r = l.split_off(l.len() / 2);
+        return count((l.parse().unwrap(), blinks - 1)) + count((r.parse().unwrap(), blinks - 1));
+    }
+
+    count((stone * 2024, blinks - 1))
+}
+
+pub fn do_part2(stones: &[usize]) -> usize {
+    // the same stones show up over and over, each of them is only counted once
+    let mut count = memoize(count_stones);
+
+    stones.iter().map(|&stone| count.get((stone, 75))).sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .split_whitespace()
+            .map(|stone| parse_at(input, stone))
+            .collect()
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(input)
+    }
+}
+
+#[test]
+fn split_digits() {
+    let e: usize = 1000;
+    let mut l = e.to_string();
+    assert_eq!(4, l.len());
+    assert!(l.len().is_multiple_of(2));
+    if l.len().is_multiple_of(2) {
+        let r = l.split_off(l.len() / 2);
+        assert_eq!(l, "10");
+        assert_eq!(r, "00");
+    }
+}
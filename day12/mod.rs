@@ -0,0 +1,59 @@
+use aoc::{Color, Grid, ParseError, Solution};
+
+pub struct Garden {
+    grid: Grid<char>,
+}
+
+impl Garden {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Garden {
+            grid: Grid::parse(input)?,
+        })
+    }
+
+    #[allow(dead_code)]
+    pub fn draw(&self) {
+        // one color per region
+        let mut render = self.grid.render();
+        for (i, region) in self.grid.regions().iter().enumerate() {
+            render = render.color(region.cells().iter().copied(), Color::cycle(i));
+        }
+        render.print();
+    }
+
+    pub fn part1(&self) -> usize {
+        let mut total_price = 0;
+        for r in self.grid.regions().iter() {
+            total_price += r.area() * r.perimeter();
+        }
+
+        total_price
+    }
+
+    pub fn part2(&self) -> usize {
+        let mut total_price = 0;
+        for r in self.grid.regions().iter() {
+            total_price += r.area() * r.sides();
+        }
+
+        total_price
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Garden;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Garden::new(input)
+    }
+
+    fn part1(grid: &Self::Input<'_>) -> impl std::fmt::Display {
+        grid.part1()
+    }
+
+    fn part2(grid: &Self::Input<'_>) -> impl std::fmt::Display {
+        grid.part2()
+    }
+}
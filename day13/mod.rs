@@ -0,0 +1,141 @@
+use aoc::{parse_at, ParseError, Solution, Vec2};
+use regex::Regex;
+
+const OFFSET: i64 = 10000000000000;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Machine {
+    button_a: Vec2<i64>,
+    button_b: Vec2<i64>,
+    prize: Vec2<i64>,
+}
+
+pub fn parse_machines(input: &str) -> Result<Vec<Machine>, ParseError> {
+    let re = Regex::new(r"X(\+|=)(\d+), Y(\+|=)(\d+)").expect("Invalid regex");
+
+    let parse_text = |s: &str| -> Result<Vec2<i64>, ParseError> {
+        let captures = re
+            .captures(s)
+            .ok_or_else(|| ParseError::at(input, s, "expected 'X+.., Y+..' or 'X=.., Y=..'"))?;
+
+        let x = parse_at(input, captures.get(2).unwrap().as_str())?;
+        let y = parse_at(input, captures.get(4).unwrap().as_str())?;
+        Ok(Vec2::new(x, y))
+    };
+
+    let mut machines = Vec::new();
+
+    let mut iter = input.lines().filter(|l| !l.is_empty());
+    while let Some(a) = iter.next() {
+        let incomplete = || {
+            ParseError::at(
+                input,
+                a,
+                "incomplete machine, expected button a, button b and prize",
+            )
+        };
+        let b = iter.next().ok_or_else(incomplete)?;
+        let c = iter.next().ok_or_else(incomplete)?;
+
+        machines.push(Machine {
+            button_a: parse_text(a)?,
+            button_b: parse_text(b)?,
+            prize: parse_text(c)?,
+        });
+    }
+
+    Ok(machines)
+}
+
+pub fn do_part1(machines: &[Machine]) -> i64 {
+    let mut total_tokens = 0;
+
+    for &Machine {
+        button_a,
+        button_b,
+        prize,
+    } in machines
+    {
+        let price_a = 3;
+        let price_b = 1;
+
+        let mut cheapest_price: Option<i64> = None;
+
+        let max_tries = 100;
+        for index_a in 0..=max_tries {
+            for index_b in 0..=max_tries {
+                if button_a * index_a + button_b * index_b == prize {
+                    let price = price_a * index_a + price_b * index_b;
+
+                    cheapest_price = match cheapest_price {
+                        None => Some(price),
+                        Some(curr) => {
+                            if price < curr {
+                                Some(price)
+                            } else {
+                                Some(curr)
+                            }
+                        }
+                    };
+                }
+            }
+        }
+
+        total_tokens += cheapest_price.unwrap_or(0);
+    }
+
+    total_tokens
+}
+
+pub fn do_part2(machines: &[Machine]) -> i64 {
+    // cramer's rule
+    let calc_linear_combination = |p: Vec2<i64>, a: Vec2<i64>, b: Vec2<i64>| -> i64 {
+        let det = a.cross(b);
+        if det == 0 {
+            return 0;
+        }
+
+        let num_a = p.cross(b);
+        let num_b = a.cross(p);
+
+        // Check if integer solution exists
+        if num_a % det != 0 || num_b % det != 0 {
+            return 0;
+        }
+
+        let a = num_a / det;
+        let b = num_b / det;
+
+        if a >= 0 && b >= 0 {
+            return 3 * a + b;
+        }
+        0
+    };
+
+    let offset = Vec2::broadcast(OFFSET);
+
+    let mut result = 0;
+    for m in machines {
+        result += calc_linear_combination(m.prize + offset, m.button_a, m.button_b);
+    }
+
+    result
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_machines(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(input)
+    }
+}
@@ -0,0 +1,137 @@
+use regex::Regex;
+use std::io;
+
+use aoc::{brent, parse_at, Counter, Frames, Image, ParseError, Rgb, Solution, Vec2};
+
+#[derive(Debug)]
+pub struct Robot {
+    position: Vec2,
+    velocity: Vec2,
+}
+
+// the robots and the floor they walk on
+pub struct Lobby {
+    robots: Vec<Robot>,
+    floor: Vec2,
+}
+
+impl Lobby {
+    fn positions(&self, blinks: i32) -> Vec<Vec2> {
+        self.robots
+            .iter()
+            .map(|robot| (robot.position + robot.velocity * blinks).rem_euclid(self.floor))
+            .collect()
+    }
+
+    // the product of the robot counts per quadrant, the middle lines don't count
+    fn safety_factor(&self, positions: &[Vec2]) -> usize {
+        let half_w = self.floor.x / 2;
+        let half_h = self.floor.y / 2;
+
+        let mut quadrants = Counter::new();
+        for pos in positions
+            .iter()
+            .filter(|pos| pos.x != half_w && pos.y != half_h)
+        {
+            let mut index = 0;
+            index += if pos.x > half_w { 1 } else { 0 };
+            index += if pos.y > half_h { 2 } else { 0 };
+            quadrants.add(index);
+        }
+
+        // an empty quadrant counts as zero
+        (0..4).map(|index| quadrants.count(&index)).product()
+    }
+}
+
+pub fn do_part1(lobby: &Lobby, blinks: i32) -> usize {
+    lobby.safety_factor(&lobby.positions(blinks))
+}
+
+pub fn do_part2(lobby: &Lobby) -> usize {
+    // the arrangements repeat, the tree has to show up within the first cycle
+    let blink = |positions: &Vec<Vec2>| -> Option<Vec<Vec2>> {
+        let next = positions
+            .iter()
+            .zip(lobby.robots.iter())
+            .map(|(&pos, robot)| (pos + robot.velocity).rem_euclid(lobby.floor))
+            .collect();
+        Some(next)
+    };
+    let cycle = brent(lobby.positions(0), blink).expect("robots always repeat");
+    let blinks = (cycle.start + cycle.length) as i32;
+
+    // most robots gather in the tree, which leaves the quadrants as uneven as they get
+    let tree = (0..blinks)
+        .min_by_key(|&blink| lobby.safety_factor(&lobby.positions(blink)))
+        .unwrap_or(0);
+
+    // frames are only a debugging aid, failing to write them doesn't fail the puzzle
+    if let Err(e) = write_frames(lobby, blinks) {
+        eprintln!("day14: no frames written: {}", e);
+    }
+
+    tree as usize
+}
+
+// with AOC_FRAMES set every arrangement becomes an image to scan through
+fn write_frames(lobby: &Lobby, blinks: i32) -> io::Result<()> {
+    let Some(mut frames) = Frames::from_env("day14")? else {
+        return Ok(());
+    };
+
+    for blink in 0..blinks {
+        let positions = lobby.positions(blink);
+        let image = Image::from_points(lobby.floor, positions, &[Rgb::BLACK, Rgb::GREEN]);
+        frames.write(blink as usize, &image)?;
+    }
+    Ok(())
+}
+
+fn parse_lobby(input: &str) -> Result<Lobby, ParseError> {
+    let re =
+        Regex::new(r"^p=(-?\d+),(-?\d+) v=(-?\d+),(-?\d+)$").expect("invalid regular expression");
+
+    let mut robots = Vec::new();
+    for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        let caps = re
+            .captures(line)
+            .ok_or_else(|| ParseError::at(input, line, "expected 'p=x,y v=x,y'"))?;
+        let number = |i| parse_at(input, caps.get(i).unwrap().as_str());
+
+        robots.push(Robot {
+            position: Vec2::new(number(1)?, number(2)?),
+            velocity: Vec2::new(number(3)?, number(4)?),
+        });
+    }
+
+    // the example robots walk on a 11x7 floor, the real ones on 101x103
+    let is_example = robots
+        .iter()
+        .all(|robot| robot.position.x < 11 && robot.position.y < 7);
+    let floor = if is_example {
+        Vec2::new(11, 7)
+    } else {
+        Vec2::new(101, 103)
+    };
+
+    Ok(Lobby { robots, floor })
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Lobby;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_lobby(input)
+    }
+
+    fn part1(lobby: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(lobby, 100)
+    }
+
+    fn part2(lobby: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(lobby)
+    }
+}
@@ -0,0 +1,270 @@
+use aoc::{CellGlyph, Direction, Frames, Grid, Image, ParseError, Rgb, Solution, Vec2};
+use itertools::Itertools;
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum Field {
+    Empty,
+    Wall,
+    Block,
+    BlockL,
+    BlockR,
+    Robot,
+}
+
+impl CellGlyph for Field {
+    fn glyph(&self) -> char {
+        match self {
+            Field::Empty => '.',
+            Field::Wall => '#',
+            Field::Block => 'O',
+            Field::BlockL => '[',
+            Field::BlockR => ']',
+            Field::Robot => '@',
+        }
+    }
+}
+
+impl TryFrom<char> for Field {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '#' => Ok(Field::Wall),
+            '.' => Ok(Field::Empty),
+            'O' => Ok(Field::Block),
+            '@' => Ok(Field::Robot),
+            _ => Err(ParseError::new(format!("unknown field '{}'", c))),
+        }
+    }
+}
+
+// walls gray, boxes yellow and the robot red
+fn frame(grid: &Grid<Field>) -> Image {
+    let palette = [Rgb::BLACK, Rgb::GRAY, Rgb::YELLOW, Rgb::RED];
+    Image::from_grid(grid, &palette, |field| match field {
+        Field::Empty => 0,
+        Field::Wall => 1,
+        Field::Block | Field::BlockL | Field::BlockR => 2,
+        Field::Robot => 3,
+    })
+}
+
+pub struct Warehouse {
+    grid: Grid<Field>,
+    robot: Vec2,
+    moves: Vec<Direction>,
+}
+
+pub fn parse_warehouse(input: &str) -> Result<Warehouse, ParseError> {
+    // the map ends at the first blank line, the moves follow
+    let mut split = input.len();
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            split = offset;
+            break;
+        }
+        offset += line.len();
+    }
+    let (map, moves) = input.split_at(split);
+
+    let (grid, markers) = Grid::<Field>::parse_with_markers(map, "@")?;
+    let robot = match markers.get(&'@').map(|robots| robots.as_slice()) {
+        Some([robot]) => *robot,
+        _ => return Err(ParseError::new("expected exactly one robot '@'")),
+    };
+
+    let mut directions = Vec::new();
+    for (i, c) in moves.char_indices() {
+        if !c.is_whitespace() {
+            let dir =
+                Direction::try_from(c).map_err(|e| e.locate(input, &moves[i..i + c.len_utf8()]))?;
+            directions.push(dir);
+        }
+    }
+
+    Ok(Warehouse {
+        grid,
+        robot,
+        moves: directions,
+    })
+}
+
+pub fn do_part1(warehouse: &Warehouse) -> usize {
+    let mut grid = warehouse.grid.clone();
+    let mut curr = warehouse.robot;
+
+    for command in warehouse.moves.iter() {
+        let dir = Vec2::from(command);
+        let next = curr + dir;
+        match grid[next] {
+            Field::Wall => {
+                // do nothing
+            }
+            Field::Block => {
+                // the first cell behind the row of blocks
+                let behind = grid
+                    .ray(&next, dir)
+                    .map(|(pos, &f)| (pos, f))
+                    .find(|&(_, f)| f != Field::Block);
+
+                if let Some((block, Field::Empty)) = behind {
+                    grid[block] = Field::Block;
+                    grid[next] = Field::Robot;
+                    grid[curr] = Field::Empty;
+                    curr = next;
+                }
+            }
+            Field::Empty => {
+                grid[curr] = Field::Empty;
+                grid[next] = Field::Robot;
+                curr = next;
+            }
+            _ => {}
+        }
+    }
+
+    let gps: i32 = grid
+        .positions_of(&Field::Block)
+        .map(|pos| pos.y * 100 + pos.x)
+        .sum();
+
+    gps as usize
+}
+
+pub fn can_move(
+    grid: &Grid<Field>,
+    pos: &Vec2,
+    dir: &Direction,
+    points: &mut Vec<(Vec2, Vec2)>,
+) -> bool {
+    // find block
+    let pos0 = *pos;
+    let pos1 = match grid[pos0] {
+        Field::BlockL => pos0 + Vec2::from(Direction::Right),
+        Field::BlockR => pos0 + Vec2::from(Direction::Left),
+        _ => panic!("can't happen"),
+    };
+
+    // next position
+    let next0 = pos0 + Vec2::from(dir);
+    let next1 = pos1 + Vec2::from(dir);
+
+    // check if we hit a wall
+    let mut can_move_to_field = |pos: &Vec2| -> bool {
+        let f = grid[*pos];
+        match f {
+            Field::Wall => false,
+            Field::Empty => true,
+            Field::BlockL | Field::BlockR => can_move(grid, pos, dir, points),
+            _ => panic!("this should not happen"),
+        }
+    };
+
+    // lateral check first
+    let check0 = next0 == pos1 || can_move_to_field(&next0);
+    let check1 = next1 == pos0 || can_move_to_field(&next1);
+
+    if check0 && check1 {
+        points.push((pos0, next0));
+        points.push((pos1, next1));
+        return true;
+    }
+
+    false
+}
+
+pub fn move_blocks(grid: &mut Grid<Field>, points: &[(Vec2, Vec2)]) {
+    // a block can be reached over two paths, the farthest cells move first so
+    // every cell moves into an empty or an already vacated one
+    let mut points: Vec<_> = points.iter().unique().collect();
+    points.sort_by_key(|(from, to)| -from.dot(*to - *from));
+
+    for (from, to) in points {
+        grid.swap(from, to);
+    }
+}
+
+pub fn do_part2(warehouse: &Warehouse) -> usize {
+    // everything except the robot is twice as wide
+    let mut grid = warehouse.grid.expand(Vec2::new(2, 1), |&f| match f {
+        Field::Block => [Field::BlockL, Field::BlockR],
+        Field::Robot => [Field::Robot, Field::Empty],
+        f => [f, f],
+    });
+    let mut curr = Vec2::new(warehouse.robot.x * 2, warehouse.robot.y);
+
+    // grid.render().print();
+
+    // with AOC_FRAMES set every move becomes an image of the animation,
+    // when they can't be written the robot moves on without them
+    let mut frames = Frames::from_env("day15").unwrap_or_else(|e| {
+        eprintln!("day15: no frames written: {}", e);
+        None
+    });
+
+    for dir in warehouse.moves.iter() {
+        let next = curr + Vec2::from(dir);
+        match grid[next] {
+            Field::Wall => {
+                // do nothing
+            }
+            Field::BlockL | Field::BlockR => {
+                let mut points = vec![];
+                if can_move(&grid, &next, dir, &mut points) {
+                    points.push((curr, next));
+
+                    move_blocks(&mut grid, &points);
+
+                    // advance
+                    curr = next;
+                }
+            }
+            Field::Empty => {
+                grid[curr] = Field::Empty;
+                grid[next] = Field::Robot;
+                curr = next;
+            }
+            _ => {}
+        }
+
+        // println!("Move {:?}:", dir);
+        // grid.render().print();
+
+        if let Some(sequence) = frames.as_mut() {
+            if let Err(e) = sequence.push(&frame(&grid)) {
+                eprintln!("day15: no more frames written: {}", e);
+                frames = None;
+            }
+        }
+
+        debug_assert_eq!(1, grid.positions_of(&Field::Robot).count());
+    }
+
+    // grid.render().print();
+
+    let gps: i32 = grid
+        .positions_of(&Field::BlockL)
+        .map(|pos| pos.y * 100 + pos.x)
+        .sum();
+
+    gps as usize
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Warehouse;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_warehouse(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(input)
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+
+use aoc::{bfs, dijkstra, dijkstra_all, CellGlyph, Direction, Grid, ParseError, Solution, Vec2};
+
+#[derive(Clone, Copy, PartialEq, Default)]
+enum Field {
+    #[default]
+    Empty,
+    Wall,
+    Start,
+    End,
+}
+
+impl TryFrom<char> for Field {
+    type Error = ParseError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Field::Wall),
+            'S' => Ok(Field::Start),
+            'E' => Ok(Field::End),
+            '.' => Ok(Field::Empty),
+            _ => Err(ParseError::new(format!("unknown field '{}'", value))),
+        }
+    }
+}
+
+impl From<Field> for char {
+    fn from(value: Field) -> Self {
+        match value {
+            Field::Empty => '.',
+            Field::Wall => '#',
+            Field::Start => 'S',
+            Field::End => 'E',
+        }
+    }
+}
+
+impl CellGlyph for Field {
+    fn glyph(&self) -> char {
+        char::from(*self)
+    }
+}
+
+pub struct Maze {
+    grid: Grid<Field>,
+    start: Vec2,
+    end: Vec2,
+}
+
+pub fn parse_maze(input: &str) -> Result<Maze, ParseError> {
+    let (grid, markers) = Grid::<Field>::parse_with_markers(input, "SE")?;
+
+    let marker = |c: char, name: &str| match markers.get(&c).map(|p| p.as_slice()) {
+        Some([pos]) => Ok(*pos),
+        Some(_) => Err(ParseError::new(format!(
+            "maze has more than one {} '{}'",
+            name, c
+        ))),
+        None => Err(ParseError::new(format!("maze has no {} '{}'", name, c))),
+    };
+
+    let (start, end) = (marker('S', "start")?, marker('E', "end")?);
+
+    // the parts can rely on a way to the end
+    let open = |pos: &Vec2| {
+        grid.neighbors4(pos)
+            .filter(|&(_, &f)| f != Field::Wall)
+            .map(|(next, _)| next)
+            .collect::<Vec<_>>()
+    };
+    if bfs([start], open, |&pos| pos == end).goal.is_none() {
+        return Err(ParseError::new("maze has no way to the end"));
+    }
+
+    Ok(Maze { grid, start, end })
+}
+
+// the reindeer starts facing east, a step costs 1 and turning 90 degrees 1000
+type Reindeer = (Vec2, Direction);
+
+fn moves(maze: &Maze, &(pos, dir): &Reindeer) -> Vec<(Reindeer, usize)> {
+    let mut moves = vec![
+        ((pos, dir.turn_left()), 1000),
+        ((pos, dir.turn_right()), 1000),
+    ];
+    let next = pos + Vec2::from(dir);
+    if maze
+        .grid
+        .value_for(&next)
+        .is_some_and(|&f| f != Field::Wall)
+    {
+        moves.push(((next, dir), 1));
+    }
+    moves
+}
+
+pub fn do_part1(maze: &Maze) -> usize {
+    let search = dijkstra(
+        [(maze.start, Direction::Right)],
+        |reindeer| moves(maze, reindeer),
+        |&(pos, _)| pos == maze.end,
+    );
+
+    search.goal_distance().expect("checked by parse_maze")
+}
+
+// the tiles on any of the cheapest paths, whichever way the reindeer faces
+pub fn do_part2(maze: &Maze) -> usize {
+    let paths = dijkstra_all(
+        [(maze.start, Direction::Right)],
+        |reindeer| moves(maze, reindeer),
+        |&(pos, _)| pos == maze.end,
+    );
+
+    let tiles: HashSet<Vec2> = paths
+        .on_paths(&paths.goals)
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect();
+    tiles.len()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse_maze(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(input)
+    }
+}
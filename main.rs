@@ -0,0 +1,314 @@
+use std::{env, fs, path::Path, process::ExitCode, time::Duration};
+
+use aoc::{bench, check_day, to_csv, to_json, BenchRow, Check, Day, Inputs, Part};
+
+// registers the days for the runner and generates one test per day
+// that checks the examples listed in dayNN/answers.toml
+macro_rules! days {
+    ($($number:literal => $module:ident::$solution:ident),* $(,)?) => {
+        $(mod $module;)*
+
+        const DAYS: &[Day] = &[$(Day::new::<$module::$solution>($number)),*];
+
+        #[cfg(test)]
+        mod examples {
+            $(
+                #[test]
+                fn $module() {
+                    super::assert_examples($number);
+                }
+            )*
+        }
+    };
+}
+
+// add new days here
+days! {
+    1 => day01::Day01,
+    2 => day02::Day02,
+    3 => day03::Day03,
+    4 => day04::Day04,
+    5 => day05::Day05,
+    6 => day06::Day06,
+    7 => day07::Day07,
+    8 => day08::Day08,
+    9 => day09::Day09,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+}
+
+// the template is never run, but has to keep up with the Solution trait
+#[cfg(test)]
+mod template;
+#[cfg(test)]
+const _: Day = Day::new::<template::DayNN>(0);
+
+const USAGE: &str = "usage: aoc run <day|all> [--part 1|2] [--input <variant>] [--input-dir <dir>]
+       aoc bench <day|all> [--runs <n>] [--warmup <n>] [--output <file.csv|file.json>]
+                           [--input <variant>] [--input-dir <dir>]
+       aoc check <day|all> [--input-dir <dir>]
+       aoc list [--input-dir <dir>]
+
+inputs are read from <dir>/dayNN/<variant>.txt, <dir> defaults to $AOC_INPUT_DIR or the current directory";
+
+fn run(day: &Day, parts: &[Part], inputs: &Inputs, variant: &str) -> Result<(), String> {
+    let input = inputs
+        .load(day.number, variant)
+        .map_err(|e| e.to_string())?;
+    let path = inputs.path(day.number, variant).display().to_string();
+
+    let answers = (day.solve)(&input, parts).map_err(|e| format!("{}: {}", path, e))?;
+    for answer in answers {
+        println!(
+            "{} {} {} ({:?})",
+            day.name(),
+            answer.part,
+            answer.value,
+            answer.elapsed
+        );
+    }
+
+    Ok(())
+}
+
+fn parse_part(arg: Option<String>) -> Result<Vec<Part>, String> {
+    match arg.as_deref() {
+        Some("1") => Ok(vec![Part::One]),
+        Some("2") => Ok(vec![Part::Two]),
+        Some(p) => Err(format!("invalid part '{}'", p)),
+        None => Err("missing value for --part".to_string()),
+    }
+}
+
+fn parse_days(arg: Option<String>) -> Result<Vec<&'static Day>, String> {
+    match arg.as_deref() {
+        Some("all") => Ok(DAYS.iter().collect()),
+        Some(n) => {
+            let number: u8 = n.parse().map_err(|_| format!("invalid day '{}'", n))?;
+            DAYS.iter()
+                .find(|d| d.number == number)
+                .map(|d| vec![d])
+                .ok_or_else(|| format!("day {} is not implemented", number))
+        }
+        None => Err("missing day".to_string()),
+    }
+}
+
+fn parse_count(flag: &str, arg: Option<String>) -> Result<usize, String> {
+    let value = parse_value(flag, arg)?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{}' for {}", value, flag))
+}
+
+fn bench_day(
+    day: &Day,
+    inputs: &Inputs,
+    variant: &str,
+    warmup: usize,
+    runs: usize,
+) -> Result<Vec<BenchRow>, String> {
+    let input = inputs
+        .load(day.number, variant)
+        .map_err(|e| e.to_string())?;
+    let path = inputs.path(day.number, variant).display().to_string();
+    bench(day, &input, warmup, runs).map_err(|e| format!("{}: {}", path, e))
+}
+
+fn print_row(row: &BenchRow) {
+    let s = &row.stats;
+    println!(
+        "{} {:<5} min {:>12?} median {:>12?} p95 {:>12?} ({} runs)",
+        row.day, row.stage, s.min, s.median, s.p95, s.runs
+    );
+}
+
+// the format follows the extension, csv unless it is .json
+fn write_rows(path: &str, rows: &[BenchRow]) -> Result<(), String> {
+    let content = match Path::new(path).extension() {
+        Some(ext) if ext == "json" => to_json(rows),
+        _ => to_csv(rows),
+    };
+    fs::write(path, content).map_err(|e| format!("{}: {}", path, e))
+}
+
+fn parse_value(flag: &str, arg: Option<String>) -> Result<String, String> {
+    arg.ok_or_else(|| format!("missing value for {}", flag))
+}
+
+struct Options {
+    command: String,
+    days: Vec<&'static Day>,
+    parts: Vec<Part>,
+    inputs: Inputs,
+    variant: String,
+    warmup: usize,
+    runs: usize,
+    output: Option<String>,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Options, String> {
+    let command = args.next().ok_or("missing command")?;
+    let days = match command.as_str() {
+        "run" | "check" | "bench" => parse_days(args.next())?,
+        "list" => DAYS.iter().collect(),
+        _ => return Err(format!("unknown command '{}'", command)),
+    };
+
+    let mut options = Options {
+        command,
+        days,
+        parts: vec![Part::One, Part::Two],
+        inputs: Inputs::from_env(),
+        variant: "input".to_string(),
+        warmup: 1,
+        runs: 10,
+        output: None,
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => options.parts = parse_part(args.next())?,
+            "--input" | "-i" => options.variant = parse_value(&arg, args.next())?,
+            "--input-dir" => options.inputs = Inputs::new(parse_value(&arg, args.next())?),
+            "--warmup" => options.warmup = parse_count(&arg, args.next())?,
+            "--runs" | "-n" => options.runs = parse_count(&arg, args.next())?.max(1),
+            "--output" | "-o" => options.output = Some(parse_value(&arg, args.next())?),
+            _ => return Err(format!("unknown argument '{}'", arg)),
+        }
+    }
+
+    Ok(options)
+}
+
+fn execute(options: Options) -> Result<(), String> {
+    let Options {
+        command,
+        days,
+        parts,
+        inputs,
+        variant,
+        warmup,
+        runs,
+        output,
+    } = options;
+
+    match command.as_str() {
+        "run" => {
+            // like bench, a failing day doesn't stop the others
+            let mut failures = 0;
+            let count = days.len();
+            for day in days {
+                if let Err(e) = run(day, &parts, &inputs, &variant) {
+                    eprintln!("error: {}", e);
+                    failures += 1;
+                }
+            }
+
+            match failures {
+                0 => Ok(()),
+                _ => Err(format!("{} of {} days failed", failures, count)),
+            }
+        }
+        "check" => {
+            let checks: Vec<Check> = days
+                .iter()
+                .flat_map(|day| check_day(day, &inputs))
+                .collect();
+            for check in checks.iter() {
+                println!("{}", check);
+            }
+
+            let failures = checks.iter().filter(|c| c.outcome.is_failure()).count();
+            println!("{} checks, {} failed", checks.len(), failures);
+
+            match failures {
+                0 => Ok(()),
+                _ => Err(format!("{} example checks failed", failures)),
+            }
+        }
+        "bench" => {
+            // a day that can't be loaded or parsed is reported, the others are still timed
+            let mut rows = Vec::new();
+            let mut failures = 0;
+            let count = days.len();
+            for day in days {
+                match bench_day(day, &inputs, &variant, warmup, runs) {
+                    Ok(rows_of_day) => {
+                        for row in rows_of_day {
+                            print_row(&row);
+                            rows.push(row);
+                        }
+                    }
+                    Err(e) => {
+                        eprintln!("error: {}", e);
+                        failures += 1;
+                    }
+                }
+            }
+
+            let totals: Vec<Duration> = rows
+                .iter()
+                .filter(|row| row.stage == "total")
+                .map(|row| row.stats.median)
+                .collect();
+            println!(
+                "{} days, total median {:?}",
+                totals.len(),
+                totals.iter().sum::<Duration>()
+            );
+
+            if let Some(path) = output {
+                write_rows(&path, &rows)?;
+            }
+
+            match failures {
+                0 => Ok(()),
+                _ => Err(format!("{} of {} days failed", failures, count)),
+            }
+        }
+        "list" => {
+            for day in days {
+                println!("{} {}", day.name(), inputs.variants(day.number).join(" "));
+            }
+            Ok(())
+        }
+        _ => unreachable!("checked by parse_args"),
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match execute(options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+fn assert_examples(number: u8) {
+    let day = DAYS.iter().find(|d| d.number == number).unwrap();
+    let failures: Vec<String> = check_day(day, &Inputs::from_env())
+        .iter()
+        .filter(|c| c.outcome.is_failure())
+        .map(|c| c.to_string())
+        .collect();
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
@@ -0,0 +1,30 @@
+use aoc::{ParseError, Solution};
+
+#[allow(unused_variables)]
+pub fn do_part1(input: &str) -> usize {
+    0
+}
+
+#[allow(unused_variables)]
+pub fn do_part2(input: &str) -> usize {
+    0
+}
+
+// copy this folder to dayNN, rename, fill in answers.toml and register the day in main.rs
+pub struct DayNN;
+
+impl Solution for DayNN {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part1(input)
+    }
+
+    fn part2(input: &Self::Input<'_>) -> impl std::fmt::Display {
+        do_part2(input)
+    }
+}